@@ -8,6 +8,29 @@ use crate::state::GameState;
 use bevy::prelude::*;
 use plugins::core::CorePlugin;
 
+/// Environment variable selecting the initial screen in debug builds
+#[cfg(debug_assertions)]
+const START_STATE_ENV: &str = "A_BEVY_OF_US_START";
+
+/// Initial GameState on launch
+///
+/// Debug builds read `A_BEVY_OF_US_START` (`match3` or `main_menu`) to skip the
+/// menu; release builds always start at the main menu.
+fn initial_state() -> GameState {
+    #[cfg(debug_assertions)]
+    if let Ok(value) = std::env::var(START_STATE_ENV) {
+        match value.to_ascii_lowercase().as_str() {
+            "match3" => {
+                info!("{START_STATE_ENV}={value}, skipping main menu");
+                return GameState::Match3;
+            }
+            "main_menu" => return GameState::MainMenu,
+            _ => warn!("Unknown {START_STATE_ENV} value {value:?}, starting at main menu"),
+        }
+    }
+    GameState::MainMenu
+}
+
 fn main() {
     #[cfg(debug_assertions)]
     {
@@ -28,8 +51,8 @@ fn main() {
     }
 
     App::new()
-        .add_plugins((DefaultPlugins, CorePlugin, UiPlugin))
+        .add_plugins((DefaultPlugins, CorePlugin, UiPlugin::default()))
         .init_state::<GameState>()
-        .insert_state(GameState::MainMenu)
+        .insert_state(initial_state())
         .run();
 }
@@ -4,6 +4,7 @@ use leafwing_input_manager::Actionlike;
 #[derive(Actionlike, PartialEq, Eq, Hash, Clone, Copy, Debug, Reflect)]
 pub enum GlobalAction {
     ToggleMenu,
+    Back,
 }
 
 #[derive(Actionlike, PartialEq, Eq, Hash, Clone, Copy, Debug, Reflect)]
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+/// Directory created under the platform config directory for the game's files
+const CONFIG_DIR_NAME: &str = "a-bevy-of-us";
+
+/// Path of `file_name` in the per-user config directory
+///
+/// None when no config directory can be found (e.g. `HOME` unset), in which
+/// case nothing is persisted.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn config_file_path(file_name: &str) -> Option<PathBuf> {
+    platform_config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(file_name))
+}
+
+/// Path of `file_name` in the per-user config directory
+///
+/// There is no file system on the web, so persistence is turned off.
+#[cfg(target_arch = "wasm32")]
+pub fn config_file_path(_file_name: &str) -> Option<PathBuf> {
+    None
+}
+
+#[cfg(target_os = "windows")]
+fn platform_config_dir() -> Option<PathBuf> {
+    env_dir("APPDATA")
+}
+
+#[cfg(target_os = "macos")]
+fn platform_config_dir() -> Option<PathBuf> {
+    env_dir("HOME").map(|home| home.join("Library").join("Application Support"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_arch = "wasm32")))]
+fn platform_config_dir() -> Option<PathBuf> {
+    env_dir("XDG_CONFIG_HOME").or_else(|| env_dir("HOME").map(|home| home.join(".config")))
+}
+
+/// Absolute directory named by an environment variable; relative paths are ignored
+#[cfg(not(target_arch = "wasm32"))]
+fn env_dir(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_files_live_in_the_game_directory() {
+        if let Some(path) = config_file_path("settings.ron") {
+            assert!(path.is_absolute());
+            assert!(path.ends_with(PathBuf::from(CONFIG_DIR_NAME).join("settings.ron")));
+        }
+    }
+}
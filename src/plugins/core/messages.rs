@@ -1,4 +1,4 @@
-use crate::plugins::core::resources::Resolution;
+use crate::plugins::core::resources::{FrameCap, Resolution};
 use bevy::prelude::*;
 use bevy::window::WindowMode;
 
@@ -8,3 +8,16 @@ pub struct ApplyDisplaySettingsMessage {
     pub resolution: Resolution,
     pub window_mode: WindowMode,
 }
+
+/// Event triggered when user changes vsync or the frame-rate cap
+#[derive(Message)]
+pub struct ApplyFrameSettingsMessage {
+    pub vsync: bool,
+    pub frame_cap: FrameCap,
+}
+
+/// Event requesting import of a settings share string
+#[derive(Message)]
+pub struct ImportSettingsMessage {
+    pub encoded: String,
+}
@@ -4,15 +4,24 @@ pub use actions::MenuNavigationAction;
 mod components;
 pub use components::GlobalInputController;
 pub use components::MenuNavigationInputController;
+pub mod config_path;
 pub mod messages;
 pub mod resources;
 pub mod systems;
 
-use crate::plugins::core::messages::ApplyDisplaySettingsMessage;
+use crate::plugins::core::messages::{
+    ApplyDisplaySettingsMessage, ApplyFrameSettingsMessage, ImportSettingsMessage,
+};
+use crate::plugins::core::resources::GameRng;
 use crate::plugins::core::systems::{
-    apply_display_settings_system, setup_display_settings, setup_global_input,
-    setup_menu_navigation_input,
+    apply_display_settings_system, apply_frame_settings_system, load_saved_settings_system,
+    restore_window_geometry_system, save_settings_system, save_window_geometry_system,
+    setup_display_settings, setup_global_input, setup_menu_navigation_input, start_game_rng,
+    track_window_geometry_system, update_window_title_system,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use crate::plugins::core::systems::frame_limiter_system;
+use crate::state::GameState;
 use bevy::prelude::*;
 use leafwing_input_manager::plugin::InputManagerPlugin;
 
@@ -22,19 +31,43 @@ impl Plugin for CorePlugin {
     fn build(&self, app: &mut App) {
         app
             // .init_resource::<DisplaySettings>()
+            .init_resource::<GameRng>()
             .add_plugins((
                 InputManagerPlugin::<GlobalAction>::default(),
                 InputManagerPlugin::<MenuNavigationAction>::default(),
             ))
             .add_message::<ApplyDisplaySettingsMessage>()
+            .add_message::<ApplyFrameSettingsMessage>()
+            .add_message::<ImportSettingsMessage>()
             .add_systems(
                 Startup,
                 (
-                    setup_display_settings,
+                    (setup_display_settings, load_saved_settings_system).chain(),
                     setup_global_input,
                     setup_menu_navigation_input,
                 ),
             )
-            .add_systems(Update, apply_display_settings_system);
+            .add_systems(OnEnter(GameState::Match3), start_game_rng)
+            .add_systems(
+                Update,
+                (
+                    (
+                        apply_display_settings_system,
+                        apply_frame_settings_system,
+                        save_settings_system,
+                    )
+                        .chain(),
+                    restore_window_geometry_system
+                        .run_if(run_once)
+                        .after(apply_display_settings_system),
+                    track_window_geometry_system,
+                    update_window_title_system,
+                ),
+            )
+            .add_systems(Last, save_window_geometry_system);
+
+        // the browser already paces frames, and wasm32 can't sleep the main thread
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Last, frame_limiter_system);
     }
 }
@@ -1,8 +1,14 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use bevy::prelude::*;
-use bevy::window::WindowMode;
+use bevy::window::{MonitorSelection, VideoModeSelection, WindowMode};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// Represents a display resolution
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Resolution {
     pub width: u32,
     pub height: u32,
@@ -20,6 +26,53 @@ impl std::fmt::Display for Resolution {
     }
 }
 
+/// Frame-rate cap applied by the frame limiter
+///
+/// Defaults to uncapped so high refresh rate displays aren't limited; vsync
+/// already paces frames unless the player turns it off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FrameCap {
+    Fps30,
+    Fps60,
+    Fps120,
+    #[default]
+    Uncapped,
+}
+
+impl FrameCap {
+    /// Minimum duration of a frame, None when uncapped
+    pub fn frame_duration(&self) -> Option<std::time::Duration> {
+        let fps = match self {
+            FrameCap::Fps30 => 30.0,
+            FrameCap::Fps60 => 60.0,
+            FrameCap::Fps120 => 120.0,
+            FrameCap::Uncapped => return None,
+        };
+        Some(std::time::Duration::from_secs_f64(1.0 / fps))
+    }
+
+    /// Cycle to the next option (30 → 60 → 120 → uncapped → 30)
+    pub fn next(&self) -> Self {
+        match self {
+            FrameCap::Fps30 => FrameCap::Fps60,
+            FrameCap::Fps60 => FrameCap::Fps120,
+            FrameCap::Fps120 => FrameCap::Uncapped,
+            FrameCap::Uncapped => FrameCap::Fps30,
+        }
+    }
+}
+
+impl std::fmt::Display for FrameCap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameCap::Fps30 => write!(f, "30"),
+            FrameCap::Fps60 => write!(f, "60"),
+            FrameCap::Fps120 => write!(f, "120"),
+            FrameCap::Uncapped => write!(f, "不限"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MonitorInfo {
     pub entity: Entity,
@@ -33,6 +86,8 @@ pub struct DisplaySettings {
     pub monitor_infos: Vec<MonitorInfo>,
     pub current_resolution: Resolution,
     pub window_mode: WindowMode,
+    pub vsync: bool,
+    pub frame_cap: FrameCap,
 }
 
 impl Default for DisplaySettings {
@@ -41,6 +96,509 @@ impl Default for DisplaySettings {
             monitor_infos: default(),
             current_resolution: Resolution::new(1280, 720),
             window_mode: WindowMode::Windowed,
+            vsync: true,
+            frame_cap: FrameCap::default(),
+        }
+    }
+}
+
+/// Version byte of the settings share string
+const SHARE_VERSION: u8 = 1;
+/// Encoded length: version, width, height, window mode, vsync, frame cap
+const SHARE_LEN: usize = 12;
+/// Accepted resolution range when importing
+const SHARE_RESOLUTION_RANGE: std::ops::RangeInclusive<u32> = 320..=16384;
+
+/// File in the config directory the player's display settings are saved to
+pub const SETTINGS_FILE_NAME: &str = "settings.ron";
+
+/// Reason a settings share string was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingsShareError {
+    Encoding,
+    Length(usize),
+    Version(u8),
+    InvalidValue(&'static str),
+}
+
+impl std::fmt::Display for SettingsShareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsShareError::Encoding => write!(f, "not a valid base64 string"),
+            SettingsShareError::Length(len) => {
+                write!(f, "expected {SHARE_LEN} bytes, got {len}")
+            }
+            SettingsShareError::Version(version) => {
+                write!(f, "unsupported version {version}")
+            }
+            SettingsShareError::InvalidValue(field) => write!(f, "invalid {field}"),
+        }
+    }
+}
+
+impl std::error::Error for SettingsShareError {}
+
+/// The player-chosen part of DisplaySettings, shareable as a short string
+///
+/// Copied to and pasted from the clipboard by the settings overlay; the
+/// settings file uses SavedSettings instead. Monitor information is machine
+/// specific and not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SharedSettings {
+    pub resolution: Resolution,
+    pub window_mode: WindowMode,
+    pub vsync: bool,
+    pub frame_cap: FrameCap,
+}
+
+impl SharedSettings {
+    pub fn from_display(settings: &DisplaySettings) -> Self {
+        Self {
+            resolution: settings.current_resolution,
+            window_mode: settings.window_mode,
+            vsync: settings.vsync,
+            frame_cap: settings.frame_cap,
+        }
+    }
+
+    /// Encode as a URL-safe base64 string
+    pub fn to_base64(&self) -> String {
+        let mut bytes = Vec::with_capacity(SHARE_LEN);
+        bytes.push(SHARE_VERSION);
+        bytes.extend_from_slice(&self.resolution.width.to_le_bytes());
+        bytes.extend_from_slice(&self.resolution.height.to_le_bytes());
+        bytes.push(match self.window_mode {
+            WindowMode::Windowed => 0,
+            WindowMode::BorderlessFullscreen(_) => 1,
+            WindowMode::Fullscreen(_, _) => 2,
+        });
+        bytes.push(u8::from(self.vsync));
+        bytes.push(match self.frame_cap {
+            FrameCap::Fps30 => 0,
+            FrameCap::Fps60 => 1,
+            FrameCap::Fps120 => 2,
+            FrameCap::Uncapped => 3,
+        });
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    /// Decode and validate a string produced by `to_base64`
+    pub fn from_base64(encoded: &str) -> Result<Self, SettingsShareError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded.trim())
+            .map_err(|_| SettingsShareError::Encoding)?;
+        if bytes.len() != SHARE_LEN {
+            return Err(SettingsShareError::Length(bytes.len()));
+        }
+        if bytes[0] != SHARE_VERSION {
+            return Err(SettingsShareError::Version(bytes[0]));
+        }
+
+        let width = u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+        let height = u32::from_le_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]);
+        if !SHARE_RESOLUTION_RANGE.contains(&width) || !SHARE_RESOLUTION_RANGE.contains(&height)
+        {
+            return Err(SettingsShareError::InvalidValue("resolution"));
+        }
+
+        let window_mode = match bytes[9] {
+            0 => WindowMode::Windowed,
+            1 => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            2 => WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current),
+            _ => return Err(SettingsShareError::InvalidValue("window mode")),
+        };
+        let vsync = match bytes[10] {
+            0 => false,
+            1 => true,
+            _ => return Err(SettingsShareError::InvalidValue("vsync")),
+        };
+        let frame_cap = match bytes[11] {
+            0 => FrameCap::Fps30,
+            1 => FrameCap::Fps60,
+            2 => FrameCap::Fps120,
+            3 => FrameCap::Uncapped,
+            _ => return Err(SettingsShareError::InvalidValue("frame cap")),
+        };
+
+        Ok(Self {
+            resolution: Resolution::new(width, height),
+            window_mode,
+            vsync,
+            frame_cap,
+        })
+    }
+}
+
+/// Window mode as stored in the settings file
+///
+/// Fullscreen modes are restored on the current monitor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SavedWindowMode {
+    Windowed,
+    BorderlessFullscreen,
+    Fullscreen,
+}
+
+impl From<WindowMode> for SavedWindowMode {
+    fn from(mode: WindowMode) -> Self {
+        match mode {
+            WindowMode::Windowed => SavedWindowMode::Windowed,
+            WindowMode::BorderlessFullscreen(_) => SavedWindowMode::BorderlessFullscreen,
+            WindowMode::Fullscreen(_, _) => SavedWindowMode::Fullscreen,
+        }
+    }
+}
+
+impl From<SavedWindowMode> for WindowMode {
+    fn from(mode: SavedWindowMode) -> Self {
+        match mode {
+            SavedWindowMode::Windowed => WindowMode::Windowed,
+            SavedWindowMode::BorderlessFullscreen => {
+                WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+            }
+            SavedWindowMode::Fullscreen => {
+                WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+            }
+        }
+    }
+}
+
+/// Reason the settings file could not be read
+#[derive(Debug)]
+pub enum SettingsFileError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for SettingsFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsFileError::Io(error) => write!(f, "could not read settings file: {error}"),
+            SettingsFileError::Parse(error) => write!(f, "invalid settings file: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SettingsFileError {}
+
+/// The player-chosen part of DisplaySettings as saved between runs
+///
+/// Stored as RON in `SETTINGS_FILE_NAME` under the per-user config directory.
+/// Kept separate from the SharedSettings share string so either format can
+/// change on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSettings {
+    pub resolution: Resolution,
+    pub window_mode: SavedWindowMode,
+    pub vsync: bool,
+    pub frame_cap: FrameCap,
+}
+
+impl SavedSettings {
+    pub fn from_display(settings: &DisplaySettings) -> Self {
+        Self {
+            resolution: settings.current_resolution,
+            window_mode: settings.window_mode.into(),
+            vsync: settings.vsync,
+            frame_cap: settings.frame_cap,
+        }
+    }
+
+    /// Read the settings file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SettingsFileError> {
+        read_ron_file(path.as_ref())
+    }
+
+    /// Write the settings file, replacing its contents
+    ///
+    /// Creates the config directory on first save.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        write_ron_file(path.as_ref(), self)
+    }
+}
+
+/// File in the config directory the window position and size are saved to
+pub const WINDOW_FILE_NAME: &str = "window.ron";
+
+/// Position and size of the primary window in physical pixels
+///
+/// Tracked while the window is windowed and saved on exit, apart from the
+/// resolution setting, so manual moving and resizing is remembered too.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowGeometry {
+    /// Read the window file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SettingsFileError> {
+        read_ron_file(path.as_ref())
+    }
+
+    /// Write the window file, replacing its contents
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        write_ron_file(path.as_ref(), self)
+    }
+
+    pub fn position(&self) -> IVec2 {
+        IVec2::new(self.x, self.y)
+    }
+
+    /// Whether the top-left corner lies on the monitor at `monitor_position`
+    pub fn is_on_monitor(&self, monitor_position: IVec2, monitor_size: UVec2) -> bool {
+        let offset = self.position() - monitor_position;
+        offset.x >= 0
+            && offset.y >= 0
+            && (offset.x as u32) < monitor_size.x
+            && (offset.y as u32) < monitor_size.y
+    }
+
+    /// Shrink and move the window so it fits entirely on the given monitor
+    pub fn clamp_to_monitor(&self, monitor_position: IVec2, monitor_size: UVec2) -> Self {
+        let width = self.width.clamp(1, monitor_size.x.max(1));
+        let height = self.height.clamp(1, monitor_size.y.max(1));
+        let max_position =
+            monitor_position + (monitor_size - UVec2::new(width, height)).as_ivec2();
+        Self {
+            x: self.x.clamp(monitor_position.x, max_position.x),
+            y: self.y.clamp(monitor_position.y, max_position.y),
+            width,
+            height,
+        }
+    }
+}
+
+fn read_ron_file<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, SettingsFileError> {
+    let contents = std::fs::read_to_string(path).map_err(SettingsFileError::Io)?;
+    ron::from_str(&contents).map_err(SettingsFileError::Parse)
+}
+
+fn write_ron_file<T: Serialize>(path: &Path, value: &T) -> std::io::Result<()> {
+    let contents = ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+        .map_err(std::io::Error::other)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, contents)
+}
+
+/// Seeded random source for all board randomness
+///
+/// Given the same seed and inputs, generation, refill and shuffle produce the same sequence.
+/// Each game draws a fresh seed unless one is pinned (a daily challenge or a
+/// seed copied from a bug report), in which case every game replays it.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    pinned: Option<u64>,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            pinned: None,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Replace the seed and restart the sequence, keeping any pinned seed
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Use this seed for every following game instead of a fresh one
+    pub fn pin_seed(&mut self, seed: u64) {
+        self.pinned = Some(seed);
+    }
+
+    /// Go back to a fresh seed per game
+    pub fn unpin_seed(&mut self) {
+        self.pinned = None;
+    }
+
+    pub fn pinned_seed(&self) -> Option<u64> {
+        self.pinned
+    }
+
+    /// Reseed for a new game: the pinned seed if set, else a fresh random one
+    pub fn start_game(&mut self) -> u64 {
+        let seed = self.pinned.unwrap_or_else(|| rand::rng().random());
+        self.reseed(seed);
+        seed
+    }
+
+    pub fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(rand::rng().random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SharedSettings {
+        SharedSettings {
+            resolution: Resolution::new(1920, 1080),
+            window_mode: WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            vsync: false,
+            frame_cap: FrameCap::Fps120,
+        }
+    }
+
+    #[test]
+    fn share_string_round_trips() {
+        let shared = sample();
+        assert_eq!(SharedSettings::from_base64(&shared.to_base64()), Ok(shared));
+    }
+
+    #[test]
+    fn share_string_tolerates_surrounding_whitespace() {
+        let shared = sample();
+        let encoded = format!("  {}\n", shared.to_base64());
+        assert_eq!(SharedSettings::from_base64(&encoded), Ok(shared));
+    }
+
+    #[test]
+    fn malformed_share_strings_are_rejected() {
+        assert_eq!(
+            SharedSettings::from_base64("not base64!"),
+            Err(SettingsShareError::Encoding)
+        );
+        assert_eq!(
+            SharedSettings::from_base64(&URL_SAFE_NO_PAD.encode([SHARE_VERSION, 0, 0])),
+            Err(SettingsShareError::Length(3))
+        );
+
+        let mut bytes = URL_SAFE_NO_PAD.decode(sample().to_base64()).unwrap();
+        bytes[0] = SHARE_VERSION + 1;
+        assert_eq!(
+            SharedSettings::from_base64(&URL_SAFE_NO_PAD.encode(&bytes)),
+            Err(SettingsShareError::Version(SHARE_VERSION + 1))
+        );
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        let valid = URL_SAFE_NO_PAD.decode(sample().to_base64()).unwrap();
+
+        let mut bytes = valid.clone();
+        bytes[1..5].copy_from_slice(&0u32.to_le_bytes());
+        assert_eq!(
+            SharedSettings::from_base64(&URL_SAFE_NO_PAD.encode(&bytes)),
+            Err(SettingsShareError::InvalidValue("resolution"))
+        );
+
+        for (index, field) in [(9, "window mode"), (10, "vsync"), (11, "frame cap")] {
+            let mut bytes = valid.clone();
+            bytes[index] = u8::MAX;
+            assert_eq!(
+                SharedSettings::from_base64(&URL_SAFE_NO_PAD.encode(&bytes)),
+                Err(SettingsShareError::InvalidValue(field))
+            );
         }
     }
+
+    fn draw(game_rng: &mut GameRng) -> Vec<u32> {
+        (0..16).map(|_| game_rng.rng().random()).collect()
+    }
+
+    #[test]
+    fn same_seed_produces_the_same_sequence() {
+        let mut first = GameRng::new(42);
+        let mut second = GameRng::new(42);
+        assert_eq!(draw(&mut first), draw(&mut second));
+    }
+
+    #[test]
+    fn pinned_seed_replays_every_game() {
+        let mut game_rng = GameRng::new(1);
+        game_rng.pin_seed(7);
+
+        assert_eq!(game_rng.start_game(), 7);
+        let first_game = draw(&mut game_rng);
+        assert_eq!(game_rng.start_game(), 7);
+        assert_eq!(draw(&mut game_rng), first_game);
+    }
+
+    #[test]
+    fn settings_file_round_trips() {
+        let dir = std::env::temp_dir().join("a-bevy-of-us-settings-test");
+        let path = dir.join(SETTINGS_FILE_NAME);
+        let saved = SavedSettings {
+            resolution: Resolution::new(2560, 1440),
+            window_mode: SavedWindowMode::Fullscreen,
+            vsync: false,
+            frame_cap: FrameCap::Fps30,
+        };
+        saved.save(&path).unwrap();
+        let loaded = SavedSettings::load(&path);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(loaded.unwrap(), saved);
+    }
+
+    #[test]
+    fn window_on_a_missing_monitor_is_moved_onto_the_current_one() {
+        let saved = WindowGeometry {
+            x: 2200,
+            y: 100,
+            width: 1280,
+            height: 720,
+        };
+        let monitor_position = IVec2::ZERO;
+        let monitor_size = UVec2::new(1920, 1080);
+        assert!(!saved.is_on_monitor(monitor_position, monitor_size));
+
+        let clamped = saved.clamp_to_monitor(monitor_position, monitor_size);
+        assert_eq!(
+            clamped,
+            WindowGeometry {
+                x: 640,
+                y: 100,
+                width: 1280,
+                height: 720,
+            }
+        );
+    }
+
+    #[test]
+    fn oversized_window_is_shrunk_to_the_monitor() {
+        let saved = WindowGeometry {
+            x: -50,
+            y: -20,
+            width: 2560,
+            height: 1440,
+        };
+        let clamped = saved.clamp_to_monitor(IVec2::new(1920, 0), UVec2::new(1920, 1080));
+        assert_eq!(
+            clamped,
+            WindowGeometry {
+                x: 1920,
+                y: 0,
+                width: 1920,
+                height: 1080,
+            }
+        );
+    }
+
+    #[test]
+    fn frame_cap_defaults_to_uncapped() {
+        assert_eq!(DisplaySettings::default().frame_cap, FrameCap::Uncapped);
+        assert_eq!(FrameCap::Uncapped.frame_duration(), None);
+    }
 }
@@ -1,10 +1,19 @@
 use crate::plugins::core::components::{GlobalInputController, MenuNavigationInputController};
-use crate::plugins::core::messages::ApplyDisplaySettingsMessage;
-use crate::plugins::core::resources::{DisplaySettings, MonitorInfo, Resolution};
+use crate::plugins::core::messages::{ApplyDisplaySettingsMessage, ApplyFrameSettingsMessage};
+use crate::plugins::core::resources::{
+    DisplaySettings, GameRng, MonitorInfo, Resolution, SETTINGS_FILE_NAME, SavedSettings,
+    SettingsFileError, WINDOW_FILE_NAME, WindowGeometry,
+};
+use crate::plugins::core::config_path::config_file_path;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
-use bevy::window::{Monitor, PrimaryWindow};
+use bevy::window::{
+    Monitor, PresentMode, PrimaryMonitor, PrimaryWindow, WindowMode, WindowPosition,
+};
 
 use crate::plugins::core::GlobalAction;
+use crate::state::GameState;
 use crate::plugins::core::actions::MenuNavigationAction;
 use leafwing_input_manager::prelude::*;
 
@@ -74,8 +83,241 @@ pub fn apply_display_settings_system(
     }
 }
 
+/// Restores the settings saved by a previous run, if any
+///
+/// Goes through the apply messages so the window is updated the same way as
+/// from the settings screen. Does nothing where there is no config directory.
+pub fn load_saved_settings_system(
+    mut apply_settings_writer: MessageWriter<ApplyDisplaySettingsMessage>,
+    mut apply_frame_writer: MessageWriter<ApplyFrameSettingsMessage>,
+) {
+    let Some(path) = config_file_path(SETTINGS_FILE_NAME) else {
+        return;
+    };
+    let saved = match SavedSettings::load(&path) {
+        Ok(saved) => saved,
+        Err(SettingsFileError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => {
+            return;
+        }
+        Err(error) => {
+            warn!("Ignoring saved settings: {}", error);
+            return;
+        }
+    };
+
+    info!("Restoring saved settings: {:?}", saved);
+    apply_settings_writer.write(ApplyDisplaySettingsMessage {
+        resolution: saved.resolution,
+        window_mode: saved.window_mode.into(),
+    });
+    apply_frame_writer.write(ApplyFrameSettingsMessage {
+        vsync: saved.vsync,
+        frame_cap: saved.frame_cap,
+    });
+}
+
+/// Writes the settings file whenever the player changes a display setting
+///
+/// This persists the resolution, window mode, vsync and frame-rate cap.
+pub fn save_settings_system(display_settings: Res<DisplaySettings>) {
+    if !display_settings.is_changed() || display_settings.is_added() {
+        return;
+    }
+    let Some(path) = config_file_path(SETTINGS_FILE_NAME) else {
+        return;
+    };
+
+    if let Err(error) = SavedSettings::from_display(&display_settings).save(&path) {
+        warn!("Could not save settings to {}: {}", path.display(), error);
+    }
+}
+
+/// Restores the window position and size saved on the last exit
+///
+/// Runs once after the saved display settings are applied, so a manually
+/// resized window keeps its size. The window is clamped to the monitor it was
+/// saved on, or to the primary monitor when that one is no longer connected.
+pub fn restore_window_geometry_system(
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+    monitors: Query<(&Monitor, Has<PrimaryMonitor>)>,
+) {
+    let Some(path) = config_file_path(WINDOW_FILE_NAME) else {
+        return;
+    };
+    let saved = match WindowGeometry::load(&path) {
+        Ok(saved) => saved,
+        Err(SettingsFileError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => {
+            return;
+        }
+        Err(error) => {
+            warn!("Ignoring saved window geometry: {}", error);
+            return;
+        }
+    };
+    let Ok(mut window) = primary_window.single_mut() else {
+        warn!("Could not find primary window to restore its geometry");
+        return;
+    };
+    if window.mode != WindowMode::Windowed {
+        return;
+    }
+
+    let monitor = monitors
+        .iter()
+        .find(|(monitor, _)| {
+            saved.is_on_monitor(monitor.physical_position, monitor.physical_size())
+        })
+        .or_else(|| monitors.iter().find(|(_, primary)| *primary))
+        .map(|(monitor, _)| monitor);
+    let geometry = match monitor {
+        Some(monitor) => saved.clamp_to_monitor(monitor.physical_position, monitor.physical_size()),
+        None => saved,
+    };
+
+    info!("Restoring window geometry: {:?}", geometry);
+    window.position = WindowPosition::At(geometry.position());
+    window
+        .resolution
+        .set_physical_resolution(geometry.width, geometry.height);
+}
+
+/// Records the primary window's position and size while it is windowed
+pub fn track_window_geometry_system(
+    mut commands: Commands,
+    primary_window: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+) {
+    let Ok(window) = primary_window.single() else {
+        return;
+    };
+    let WindowPosition::At(position) = window.position else {
+        return;
+    };
+    if window.mode != WindowMode::Windowed {
+        return;
+    }
+
+    let size = window.physical_size();
+    commands.insert_resource(WindowGeometry {
+        x: position.x,
+        y: position.y,
+        width: size.x,
+        height: size.y,
+    });
+}
+
+/// Writes the last windowed position and size when the app exits
+pub fn save_window_geometry_system(
+    mut reader: MessageReader<AppExit>,
+    geometry: Option<Res<WindowGeometry>>,
+) {
+    if reader.read().count() == 0 {
+        return;
+    }
+    let (Some(geometry), Some(path)) = (geometry, config_file_path(WINDOW_FILE_NAME)) else {
+        return;
+    };
+
+    if let Err(error) = geometry.save(&path) {
+        warn!(
+            "Could not save window geometry to {}: {}",
+            path.display(),
+            error
+        );
+    }
+}
+
+/// Base window title, followed by the current screen
+pub const BASE_WINDOW_TITLE: &str = "A Bevy Of Us";
+
+/// System that reflects the current GameState in the window title
+///
+/// Only writes on state changes, so the title isn't touched every frame.
+pub fn update_window_title_system(
+    game_state: Res<State<GameState>>,
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !game_state.is_changed() {
+        return;
+    }
+
+    let screen = match game_state.get() {
+        GameState::MainMenu => "主菜单",
+        GameState::Match3 => "游戏中",
+    };
+
+    if let Ok(mut window) = primary_window.single_mut() {
+        window.title = format!("{} — {}", BASE_WINDOW_TITLE, screen);
+    }
+}
+
+/// Seeds the board random sequence for a new game and logs the seed for reproduction
+///
+/// Uses the pinned seed when one is set, otherwise a fresh seed per game.
+pub fn start_game_rng(mut game_rng: ResMut<GameRng>) {
+    let pinned = game_rng.pinned_seed().is_some();
+    let seed = game_rng.start_game();
+    if pinned {
+        info!("Match3 started with pinned seed {}", seed);
+    } else {
+        info!("Match3 started with seed {}", seed);
+    }
+}
+
+/// System that applies vsync and frame-rate cap changes
+///
+/// The present mode is switched on the live window; the Auto* modes fall back
+/// to a supported mode when the backend lacks the exact one, so no restart is
+/// needed. The cap itself is enforced by `frame_limiter_system`; on the web the
+/// browser paces frames and the cap has no effect.
+pub fn apply_frame_settings_system(
+    mut display_settings: ResMut<DisplaySettings>,
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+    mut reader: MessageReader<ApplyFrameSettingsMessage>,
+) {
+    for event in reader.read() {
+        info!(
+            "Applying frame settings: vsync {}, cap {}",
+            event.vsync, event.frame_cap
+        );
+        display_settings.vsync = event.vsync;
+        display_settings.frame_cap = event.frame_cap;
+
+        if let Ok(mut window) = primary_window.single_mut() {
+            window.present_mode = if event.vsync {
+                PresentMode::AutoVsync
+            } else {
+                PresentMode::AutoNoVsync
+            };
+        } else {
+            warn!("Could not find primary window to apply frame settings");
+        }
+    }
+}
+
+/// System that sleeps at the end of the frame to honour the frame-rate cap
+///
+/// Not available on wasm32, where the main thread can't sleep.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn frame_limiter_system(
+    display_settings: Res<DisplaySettings>,
+    mut last_frame: Local<Option<Instant>>,
+) {
+    if let Some(target) = display_settings.frame_cap.frame_duration()
+        && let Some(last) = *last_frame
+    {
+        let elapsed = last.elapsed();
+        if elapsed < target {
+            std::thread::sleep(target - elapsed);
+        }
+    }
+    *last_frame = Some(Instant::now());
+}
+
 pub fn setup_global_input(mut commands: Commands) {
-    let input_map = InputMap::new([(GlobalAction::ToggleMenu, KeyCode::Escape)]);
+    let input_map = InputMap::new([
+        (GlobalAction::ToggleMenu, KeyCode::Escape),
+        (GlobalAction::Back, KeyCode::Backspace),
+    ]);
     commands.spawn(input_map).insert(GlobalInputController);
 }
 
@@ -1,8 +1,15 @@
-use crate::plugins::ui::components::{Focusable, Focused};
+use crate::plugins::ui::components::{
+    Disabled, Focusable, Focused, Selected, ThemedFontSize, ThemedText,
+};
+use crate::plugins::ui::fonts::FontProvider;
 use crate::plugins::ui::navigation::{NavigationGraph, NavigationNeighbors};
-use crate::plugins::ui::styles::Theme;
+use crate::plugins::ui::styles::{Theme, ThemedColor, color_hex};
+use crate::plugins::ui::systems::navigation::ButtonStyleResource;
+use crate::plugins::ui::telemetry::ButtonActionName;
+use crate::plugins::ui::ui_builders::TextLevel;
 use bevy::ecs::relationship::RelatedSpawnerCommands;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Button style constants
 pub const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
@@ -12,13 +19,21 @@ pub const FOCUSED_BUTTON: Color = Color::srgb(0.3, 0.3, 0.5);
 pub const SELECTED_BUTTON: Color = Color::srgb(0.2, 0.5, 0.2);
 
 /// Button style configuration
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ButtonStyle {
+    #[serde(with = "color_hex")]
     pub normal: Color,
+    #[serde(with = "color_hex")]
     pub hovered: Color,
+    #[serde(with = "color_hex")]
     pub pressed: Color,
+    #[serde(with = "color_hex")]
     pub focused: Color,
+    #[serde(with = "color_hex")]
     pub selected: Color,
+    /// 圆角半径 (像素)，0 表示直角
+    #[serde(default)]
+    pub border_radius: f32,
 }
 
 impl Default for ButtonStyle {
@@ -29,46 +44,223 @@ impl Default for ButtonStyle {
             pressed: PRESSED_BUTTON,
             focused: FOCUSED_BUTTON,
             selected: SELECTED_BUTTON,
+            border_radius: 0.0,
         }
     }
 }
 
+/// Per-button style that takes precedence over the theme button style
+#[derive(Component, Clone, Debug)]
+pub struct ButtonStyleOverride(pub ButtonStyle);
+
+/// 按钮样式在主题中的角色，切换主题时据此重新取样式
+///
+/// 普通按钮不需要角色，直接使用 `Theme.button`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonRole {
+    /// 危险操作 (例如退出)，即 `Theme.button_danger`
+    Danger,
+    /// 浮层关闭(×)按钮：表面色底，悬停/按下时为 `error` 色
+    Close,
+}
+
+impl ButtonRole {
+    /// 从主题解析出该角色的按钮样式
+    pub fn resolve(&self, theme: &Theme) -> ButtonStyle {
+        match self {
+            ButtonRole::Danger => theme.button_danger.clone(),
+            ButtonRole::Close => ButtonStyle {
+                normal: theme.colors.surface,
+                hovered: theme.colors.error,
+                pressed: theme.colors.error,
+                ..theme.button.clone()
+            },
+        }
+    }
+}
+
+/// 标记按钮样式跟随主题角色，主题变化时重新解析 `ButtonStyleOverride` 和圆角
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ThemedButtonStyle(pub ButtonRole);
+
+/// Per-button deviations from the theme, unset fields use the theme defaults
+#[derive(Clone, Debug, Default)]
+pub struct ButtonOverrides {
+    /// Fixed style, kept as is when the theme changes
+    pub style: Option<ButtonStyle>,
+    /// Style role resolved from the theme, and again on every theme change
+    pub role: Option<ButtonRole>,
+    pub text_color: Option<Color>,
+    /// Leading icon shown left of the label
+    pub icon: Option<Handle<Image>>,
+}
+
+impl ButtonOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn with_role(mut self, role: ButtonRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn with_text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    pub fn with_icon(mut self, icon: Handle<Image>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
 /// Navigation layout type
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NavigationLayout {
     Vertical,
     Horizontal,
     Grid { columns: usize },
+    /// Rows of horizontally navigable controls, added with `add_row`
+    ///
+    /// Left/Right move within a row; Up/Down move to the first control of the
+    /// neighbouring row.
+    Rows,
 }
 
 /// Builder for creating navigable buttons
 pub struct ButtonNavigationBuilder {
     buttons: Vec<Entity>,
+    /// Length of each row in `buttons`, used by `NavigationLayout::Rows`
+    row_lens: Vec<usize>,
     layout: NavigationLayout,
+    wrap: bool,
+    /// Buttons shown greyed out and left out of navigation
+    disabled: Vec<Entity>,
 }
 
 impl ButtonNavigationBuilder {
     pub fn new(layout: NavigationLayout) -> Self {
         Self {
             buttons: Vec::new(),
+            row_lens: Vec::new(),
             layout,
+            wrap: false,
+            disabled: Vec::new(),
         }
     }
 
+    /// Wrap focus around at the edges (last → first and first → last)
+    ///
+    /// Applies to both axes of Grid and Rows layouts. Off by default.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     /// Add a button to the builder
     pub fn add_button(&mut self, entity: Entity) {
         self.buttons.push(entity);
+        self.row_lens.push(1);
+    }
+
+    /// Add a button that renders disabled and is left out of navigation
+    ///
+    /// The buttons on either side are linked directly to each other. `build`
+    /// inserts `Disabled`, so the button uses `text_disabled` and never gets
+    /// `Focused`; remove the marker and rebuild to enable it again.
+    pub fn add_button_disabled(&mut self, entity: Entity) {
+        self.disabled.push(entity);
+    }
+
+    /// Add a row of buttons navigated as a group
+    ///
+    /// Only `NavigationLayout::Rows` treats the row as a group; other layouts
+    /// see the buttons in order as if added one by one.
+    pub fn add_row(&mut self, row: impl IntoIterator<Item = Entity>) {
+        let start = self.buttons.len();
+        self.buttons.extend(row);
+        let len = self.buttons.len() - start;
+        if len > 0 {
+            self.row_lens.push(len);
+        }
+    }
+
+    /// Split the buttons into rows, one row per button if the order was rearranged
+    fn rows(&self) -> Vec<&[Entity]> {
+        if self.row_lens.iter().sum::<usize>() != self.buttons.len() {
+            return self.buttons.chunks(1).collect();
+        }
+
+        let mut rows = Vec::with_capacity(self.row_lens.len());
+        let mut start = 0;
+        for &len in &self.row_lens {
+            rows.push(&self.buttons[start..start + len]);
+            start += len;
+        }
+        rows
+    }
+
+    /// Insert a button at a navigation index, independent of spawn order
+    ///
+    /// An index inside a row (after its first button) joins that row; an index
+    /// at a row boundary gets a row of its own, like `add_button`. Indices
+    /// past the end append the button.
+    pub fn add_button_at(&mut self, index: usize, entity: Entity) {
+        let index = index.min(self.buttons.len());
+        self.buttons.insert(index, entity);
+
+        // 找到 index 所在的行，以及它是否正好是该行的第一个位置
+        let mut row_start = 0;
+        let position = self.row_lens.iter().enumerate().find_map(|(row, &len)| {
+            let start = row_start;
+            row_start += len;
+            (index < start + len).then_some((row, index == start))
+        });
+        match position {
+            Some((row, true)) => self.row_lens.insert(row, 1),
+            Some((row, false)) => self.row_lens[row] += 1,
+            None => self.row_lens.push(1),
+        }
+    }
+
+    /// Replace the navigation order with an explicit list of buttons
+    ///
+    /// Neighbors are wired following this order on `build`, so an existing
+    /// menu can be re-linked without respawning its buttons.
+    pub fn with_order(mut self, order: Vec<Entity>) -> Self {
+        self.row_lens = vec![1; order.len()];
+        self.buttons = order;
+        self
     }
 
     /// Build the navigation graph and set up initial focus
+    ///
+    /// Returns the first button in navigation order, or None for an empty
+    /// builder so dynamically generated menus can show an empty state.
+    /// Back/Escape don't depend on focus and keep working either way.
     pub fn build(
         self,
         commands: &mut Commands,
         nav_graph: &mut NavigationGraph,
         set_initial_focus: bool,
-    ) {
+    ) -> Option<Entity> {
+        for &entity in &self.disabled {
+            commands.entity(entity).insert(Disabled);
+        }
+
         if self.buttons.is_empty() {
-            return;
+            warn!("ButtonNavigationBuilder::build called without buttons, nothing to focus");
+            if set_initial_focus {
+                nav_graph.clear_focus();
+            }
+            return None;
         }
 
         // Add Focusable to all buttons
@@ -79,15 +271,20 @@ impl ButtonNavigationBuilder {
         // Create navigation relationships based on layout
         match self.layout {
             NavigationLayout::Vertical => {
+                let wrap = self.wrap && self.buttons.len() > 1;
                 for (i, &entity) in self.buttons.iter().enumerate() {
                     let neighbors = NavigationNeighbors::new()
                         .with_up(if i > 0 {
                             Some(self.buttons[i - 1])
+                        } else if wrap {
+                            self.buttons.last().copied()
                         } else {
                             None
                         })
                         .with_down(if i < self.buttons.len() - 1 {
                             Some(self.buttons[i + 1])
+                        } else if wrap {
+                            self.buttons.first().copied()
                         } else {
                             None
                         });
@@ -95,15 +292,20 @@ impl ButtonNavigationBuilder {
                 }
             }
             NavigationLayout::Horizontal => {
+                let wrap = self.wrap && self.buttons.len() > 1;
                 for (i, &entity) in self.buttons.iter().enumerate() {
                     let neighbors = NavigationNeighbors::new()
                         .with_left(if i > 0 {
                             Some(self.buttons[i - 1])
+                        } else if wrap {
+                            self.buttons.last().copied()
                         } else {
                             None
                         })
                         .with_right(if i < self.buttons.len() - 1 {
                             Some(self.buttons[i + 1])
+                        } else if wrap {
+                            self.buttons.first().copied()
                         } else {
                             None
                         });
@@ -111,6 +313,7 @@ impl ButtonNavigationBuilder {
                 }
             }
             NavigationLayout::Grid { columns } => {
+                let rows_count = self.buttons.len().div_ceil(columns);
                 for (i, &entity) in self.buttons.iter().enumerate() {
                     let row = i / columns;
                     let col = i % columns;
@@ -148,6 +351,26 @@ impl ButtonNavigationBuilder {
                         None
                     };
 
+                    // 环绕：行首尾互连，列首尾互连 (末行不满时跳到该列最后一个按钮)
+                    let (up, down, left, right) = if self.wrap {
+                        let row_start = row * columns;
+                        let row_end = (row_start + columns).min(self.buttons.len()) - 1;
+                        let last_in_col = (0..rows_count)
+                            .rev()
+                            .map(|r| r * columns + col)
+                            .find(|&idx| idx < self.buttons.len())
+                            .unwrap_or(i);
+                        let wrap_to = |idx: usize| (idx != i).then(|| self.buttons[idx]);
+                        (
+                            up.or_else(|| wrap_to(last_in_col)),
+                            down.or_else(|| wrap_to(col)),
+                            left.or_else(|| wrap_to(row_end)),
+                            right.or_else(|| wrap_to(row_start)),
+                        )
+                    } else {
+                        (up, down, left, right)
+                    };
+
                     let neighbors = NavigationNeighbors::new()
                         .with_up(up)
                         .with_down(down)
@@ -156,15 +379,53 @@ impl ButtonNavigationBuilder {
                     nav_graph.register_button(entity, neighbors);
                 }
             }
+            NavigationLayout::Rows => {
+                let rows = self.rows();
+                let wrap_rows = self.wrap && rows.len() > 1;
+                for (row_idx, row) in rows.iter().enumerate() {
+                    // 进入相邻行时聚焦该行第一个控件
+                    let up = match row_idx.checked_sub(1) {
+                        Some(idx) => rows[idx].first().copied(),
+                        None if wrap_rows => rows.last().and_then(|row| row.first().copied()),
+                        None => None,
+                    };
+                    let down = match rows.get(row_idx + 1) {
+                        Some(next) => next.first().copied(),
+                        None if wrap_rows => rows[0].first().copied(),
+                        None => None,
+                    };
+                    let wrap_cols = self.wrap && row.len() > 1;
+
+                    for (col, &entity) in row.iter().enumerate() {
+                        let left = match col.checked_sub(1) {
+                            Some(idx) => Some(row[idx]),
+                            None if wrap_cols => row.last().copied(),
+                            None => None,
+                        };
+                        let right = match row.get(col + 1) {
+                            Some(&next) => Some(next),
+                            None if wrap_cols => row.first().copied(),
+                            None => None,
+                        };
+
+                        let neighbors = NavigationNeighbors::new()
+                            .with_up(up)
+                            .with_down(down)
+                            .with_left(left)
+                            .with_right(right);
+                        nav_graph.register_button(entity, neighbors);
+                    }
+                }
+            }
         }
 
         // Set initial focus
+        let first_button = self.buttons[0];
         if set_initial_focus {
-            if let Some(&first_button) = self.buttons.first() {
-                commands.entity(first_button).insert(Focused);
-                nav_graph.set_focus(first_button);
-            }
+            commands.entity(first_button).insert(Focused);
+            nav_graph.set_focus(first_button);
         }
+        Some(first_button)
     }
 }
 
@@ -222,6 +483,12 @@ impl ButtonSize {
             ButtonSize::Custom(w, h) => (*w, *h),
         }
     }
+
+    /// 乘以缩放系数后的尺寸 (包括 `Custom`)，系数通常取 `theme.ui_scale`
+    pub fn scaled_dimensions(&self, scale: f32) -> (f32, f32) {
+        let (width, height) = self.dimensions();
+        (width * scale, height * scale)
+    }
 }
 
 /// 增强的按钮spawn函数，从主题获取样式
@@ -230,13 +497,38 @@ pub fn spawn_themed_button<T: Component>(
     text: &str,
     action: T,
     theme: &Theme,
-    asset_server: &AssetServer,
+    asset_server: &impl FontProvider,
     width: f32,
     height: f32,
 ) -> Entity {
+    spawn_button_internal(
+        parent,
+        text,
+        action,
+        theme,
+        asset_server,
+        width,
+        height,
+        ButtonOverrides::default(),
+    )
+}
+
+/// 生成按钮，可选覆盖主题的按钮样式和文字颜色
+#[allow(clippy::too_many_arguments)]
+fn spawn_button_internal<T: Component>(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    text: &str,
+    action: T,
+    theme: &Theme,
+    asset_server: &impl FontProvider,
+    width: f32,
+    height: f32,
+    overrides: ButtonOverrides,
+) -> Entity {
+    let font_size = TextLevel::Body.font_size(&theme.typography);
     let font = TextFont {
-        font: asset_server.load(&theme.typography.font_path),
-        font_size: theme.typography.size_body,
+        font: asset_server.font(&theme.typography.font_path),
+        font_size,
         ..default()
     };
 
@@ -246,23 +538,69 @@ pub fn spawn_themed_button<T: Component>(
         margin: UiRect::all(Val::Px(theme.spacing.sm)),
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
+        column_gap: Val::Px(theme.spacing.sm),
         ..default()
     };
 
-    parent
-        .spawn((
-            Button,
-            node,
-            BackgroundColor(theme.button.normal),
-            action,
-        ))
+    let text_color = overrides.text_color.unwrap_or(theme.colors.text_primary);
+    // 角色优先于固定样式，二者都未设置时使用主题按钮样式
+    let style = match (overrides.role, overrides.style) {
+        (Some(role), _) => Some(role.resolve(theme)),
+        (None, style) => style,
+    };
+    let (normal, border_radius) = style
+        .as_ref()
+        .map_or((theme.button.normal, theme.button.border_radius), |style| {
+            (style.normal, style.border_radius)
+        });
+
+    let mut button = parent.spawn((
+        Button,
+        node,
+        BackgroundColor(normal),
+        action,
+        ButtonActionName::of::<T>(),
+    ));
+
+    // 直角按钮不保留 BorderRadius (Node 会带上默认值)
+    if border_radius > 0.0 {
+        button.insert(BorderRadius::all(Val::Px(border_radius)));
+    } else {
+        button.remove::<BorderRadius>();
+    }
+
+    if let Some(style) = style {
+        button.insert(ButtonStyleOverride(style));
+    }
+    if let Some(role) = overrides.role {
+        button.insert(ThemedButtonStyle(role));
+    }
+
+    let themed_text = overrides.text_color.is_none();
+    button
         .with_children(|button| {
-            button.spawn((
+            // 图标与正文等高
+            if let Some(icon) = overrides.icon {
+                button.spawn((
+                    ImageNode::new(icon),
+                    Node {
+                        width: Val::Px(font_size),
+                        height: Val::Px(font_size),
+                        ..default()
+                    },
+                ));
+            }
+
+            let mut label = button.spawn((
                 Text::new(text),
                 font,
-                TextColor(theme.colors.text_primary),
+                TextColor(text_color),
                 TextLayout::new_with_justify(Justify::Center),
+                ThemedFontSize(TextLevel::Body),
             ));
+            if themed_text {
+                label.insert(ThemedText(ThemedColor::TextPrimary));
+            }
         })
         .id()
 }
@@ -273,9 +611,254 @@ pub fn spawn_button_sized<T: Component>(
     text: &str,
     action: T,
     theme: &Theme,
-    asset_server: &AssetServer,
+    asset_server: &impl FontProvider,
     size: ButtonSize,
 ) -> Entity {
-    let (width, height) = size.dimensions();
+    let (width, height) = size.scaled_dimensions(theme.ui_scale);
     spawn_themed_button(parent, text, action, theme, asset_server, width, height)
 }
+
+/// 使用预设尺寸spawn按钮，并覆盖部分主题样式 (例如危险操作的红色按钮)
+pub fn spawn_styled_button<T: Component>(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    text: &str,
+    action: T,
+    theme: &Theme,
+    asset_server: &impl FontProvider,
+    size: ButtonSize,
+    overrides: ButtonOverrides,
+) -> Entity {
+    let (width, height) = size.scaled_dimensions(theme.ui_scale);
+    spawn_button_internal(
+        parent,
+        text,
+        action,
+        theme,
+        asset_server,
+        width,
+        height,
+        overrides,
+    )
+}
+
+/// 使用预设尺寸spawn带前置图标的按钮 (例如设置按钮的齿轮)
+///
+/// 图标边长取缩放后的正文字号，与文本等高
+pub fn spawn_button_with_icon<T: Component>(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    text: &str,
+    icon: Handle<Image>,
+    action: T,
+    theme: &Theme,
+    asset_server: &impl FontProvider,
+    size: ButtonSize,
+) -> Entity {
+    spawn_styled_button(
+        parent,
+        text,
+        action,
+        theme,
+        asset_server,
+        size,
+        ButtonOverrides::new().with_icon(icon),
+    )
+}
+
+/// 按钮视觉状态系统，对所有按钮生效 (不限主菜单)
+///
+/// 背景色优先级：Pressed > Hovered > Focused > Selected > Normal。
+/// 鼠标悬停在已聚焦的按钮上时显示悬停色，移开后恢复聚焦色。
+/// 颜色取自 `ButtonStyleResource` (与 `Theme.button` 同步)，
+/// 带 `ButtonStyleOverride` 的按钮使用自身样式，禁用按钮由禁用样式系统处理
+pub fn button_visual_state_system(
+    mut q_buttons: Query<
+        (
+            &mut BackgroundColor,
+            &Interaction,
+            Has<Focused>,
+            Has<Selected>,
+            Option<&ButtonStyleOverride>,
+        ),
+        (With<Button>, Without<Disabled>),
+    >,
+    button_style: Res<ButtonStyleResource>,
+) {
+    for (mut bg_color, interaction, focused, selected, style_override) in q_buttons.iter_mut() {
+        let style = style_override.map_or(&button_style.0, |o| &o.0);
+        let new_color = match *interaction {
+            Interaction::Pressed => style.pressed,
+            Interaction::Hovered => style.hovered,
+            Interaction::None if focused => style.focused,
+            Interaction::None if selected => style.selected,
+            Interaction::None => style.normal,
+        };
+
+        // 仅在颜色变化时写入，避免每帧触发变更检测
+        if bg_color.0 != new_color {
+            bg_color.0 = new_color;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::ui::navigation::Direction::{self, Down, Left, Right, Up};
+    use crate::plugins::ui::testing::{build_navigation, minimal_ui_app, spawn_buttons};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[derive(Component)]
+    struct TestAction;
+
+    /// Fonts aren't loaded in tests, text gets the default handle
+    struct NoFonts;
+
+    impl FontProvider for NoFonts {
+        fn font(&self, _path: &str) -> Handle<Font> {
+            Handle::default()
+        }
+    }
+
+    /// Spawn one themed button whose theme uses the given corner radius
+    fn spawn_with_radius(app: &mut App, border_radius: f32) -> Entity {
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                let mut theme = Theme::default();
+                theme.button.border_radius = border_radius;
+                let mut button = Entity::PLACEHOLDER;
+                commands.spawn(Node::default()).with_children(|parent| {
+                    button = spawn_button_sized(
+                        parent,
+                        "OK",
+                        TestAction,
+                        &theme,
+                        &NoFonts,
+                        ButtonSize::Small,
+                    );
+                });
+                button
+            })
+            .expect("button spawn system should run")
+    }
+
+    #[test]
+    fn theme_border_radius_is_applied_to_buttons() {
+        let mut app = minimal_ui_app();
+        let button = spawn_with_radius(&mut app, 6.0);
+
+        assert_eq!(
+            app.world().get::<BorderRadius>(button),
+            Some(&BorderRadius::all(Val::Px(6.0)))
+        );
+    }
+
+    #[test]
+    fn zero_border_radius_spawns_no_border_radius() {
+        let mut app = minimal_ui_app();
+        let button = spawn_with_radius(&mut app, 0.0);
+
+        assert!(app.world().get::<Button>(button).is_some());
+        assert!(app.world().get::<BorderRadius>(button).is_none());
+    }
+
+    fn neighbor(app: &App, entity: Entity, direction: Direction) -> Option<Entity> {
+        app.world()
+            .resource::<NavigationGraph>()
+            .get_neighbor(entity, direction)
+    }
+
+    #[test]
+    fn add_button_at_reorders_neighbours() {
+        let mut app = minimal_ui_app();
+        let buttons = spawn_buttons(&mut app, 3);
+        let mut builder = ButtonNavigationBuilder::new(NavigationLayout::Vertical);
+        builder.add_button(buttons[0]);
+        builder.add_button(buttons[1]);
+        builder.add_button_at(0, buttons[2]);
+
+        let first = build_navigation(&mut app, builder, false);
+
+        assert_eq!(first, Some(buttons[2]));
+        assert_eq!(neighbor(&app, buttons[2], Down), Some(buttons[0]));
+        assert_eq!(neighbor(&app, buttons[0], Up), Some(buttons[2]));
+        assert_eq!(neighbor(&app, buttons[0], Down), Some(buttons[1]));
+        assert_eq!(neighbor(&app, buttons[1], Down), None);
+    }
+
+    #[test]
+    fn add_button_at_joins_the_row_it_lands_in() {
+        let mut app = minimal_ui_app();
+        let buttons = spawn_buttons(&mut app, 4);
+        let mut builder = ButtonNavigationBuilder::new(NavigationLayout::Rows);
+        builder.add_row([buttons[0], buttons[1]]);
+        builder.add_row([buttons[2]]);
+        builder.add_button_at(1, buttons[3]);
+
+        build_navigation(&mut app, builder, false);
+
+        assert_eq!(neighbor(&app, buttons[0], Right), Some(buttons[3]));
+        assert_eq!(neighbor(&app, buttons[3], Right), Some(buttons[1]));
+        assert_eq!(neighbor(&app, buttons[3], Down), Some(buttons[2]));
+        assert_eq!(neighbor(&app, buttons[2], Up), Some(buttons[0]));
+    }
+
+    #[test]
+    fn rows_layout_moves_within_and_across_rows() {
+        let mut app = minimal_ui_app();
+        let buttons = spawn_buttons(&mut app, 5);
+        let mut builder = ButtonNavigationBuilder::new(NavigationLayout::Rows);
+        builder.add_row([buttons[0], buttons[1], buttons[2]]);
+        builder.add_row([buttons[3], buttons[4]]);
+
+        build_navigation(&mut app, builder, false);
+
+        // 行内左右移动，不跨行
+        assert_eq!(neighbor(&app, buttons[0], Right), Some(buttons[1]));
+        assert_eq!(neighbor(&app, buttons[1], Left), Some(buttons[0]));
+        assert_eq!(neighbor(&app, buttons[2], Right), None);
+        assert_eq!(neighbor(&app, buttons[3], Right), Some(buttons[4]));
+        assert_eq!(neighbor(&app, buttons[3], Left), None);
+
+        // 上下移动到相邻行的第一个控件
+        assert_eq!(neighbor(&app, buttons[2], Down), Some(buttons[3]));
+        assert_eq!(neighbor(&app, buttons[4], Up), Some(buttons[0]));
+        assert_eq!(neighbor(&app, buttons[0], Up), None);
+        assert_eq!(neighbor(&app, buttons[3], Down), None);
+    }
+
+    #[test]
+    fn empty_builder_returns_none_and_clears_focus() {
+        let mut app = minimal_ui_app();
+        let stale = spawn_buttons(&mut app, 1)[0];
+        app.world_mut()
+            .resource_mut::<NavigationGraph>()
+            .set_focus(stale);
+
+        let builder = ButtonNavigationBuilder::new(NavigationLayout::Vertical);
+        let first = build_navigation(&mut app, builder, true);
+
+        assert_eq!(first, None);
+        assert_eq!(
+            app.world().resource::<NavigationGraph>().get_focused(),
+            None
+        );
+    }
+
+    #[test]
+    fn single_button_is_focused_without_neighbours() {
+        let mut app = minimal_ui_app();
+        let button = spawn_buttons(&mut app, 1)[0];
+        let mut builder = ButtonNavigationBuilder::new(NavigationLayout::Vertical).wrap(true);
+        builder.add_button(button);
+
+        let first = build_navigation(&mut app, builder, true);
+
+        assert_eq!(first, Some(button));
+        assert!(app.world().get::<Focused>(button).is_some());
+        let graph = app.world().resource::<NavigationGraph>();
+        assert_eq!(graph.get_focused(), Some(button));
+        for direction in [Up, Down, Left, Right] {
+            assert_eq!(graph.get_neighbor(button, direction), None);
+        }
+    }
+}
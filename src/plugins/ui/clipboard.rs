@@ -0,0 +1,41 @@
+/// Reason the system clipboard could not be used
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardError(String);
+
+impl std::fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "clipboard unavailable: {}", self.0)
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// Put text on the system clipboard
+#[cfg(not(target_arch = "wasm32"))]
+pub fn copy_text(text: &str) -> Result<(), ClipboardError> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|error| ClipboardError(error.to_string()))
+}
+
+/// Read text from the system clipboard
+#[cfg(not(target_arch = "wasm32"))]
+pub fn paste_text() -> Result<String, ClipboardError> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|error| ClipboardError(error.to_string()))
+}
+
+/// Put text on the system clipboard
+///
+/// The browser clipboard is asynchronous and not wired up yet.
+#[cfg(target_arch = "wasm32")]
+pub fn copy_text(_text: &str) -> Result<(), ClipboardError> {
+    Err(ClipboardError("not supported on the web".to_string()))
+}
+
+/// Read text from the system clipboard
+#[cfg(target_arch = "wasm32")]
+pub fn paste_text() -> Result<String, ClipboardError> {
+    Err(ClipboardError("not supported on the web".to_string()))
+}
@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use crate::plugins::ui::styles::{ThemeSpacing, ThemedColor};
+use crate::plugins::ui::ui_builders::TextLevel;
 
 /// Marker component for buttons that can be focused
 #[derive(Component)]
@@ -8,6 +12,158 @@ pub struct Focusable;
 #[derive(Component)]
 pub struct Focused;
 
+/// Disables a widget: dimmed colours, no pointer interaction and skipped by navigation
+///
+/// Removing the marker restores the colours cached in DisabledColorCache.
+#[derive(Component)]
+pub struct Disabled;
+
+/// Colours a widget had before it was disabled
+#[derive(Component)]
+pub struct DisabledColorCache {
+    pub background: Option<Color>,
+    pub text: Vec<(Entity, Color)>,
+}
+
 /// Component indicating a button is in a selected state (e.g., current resolution)
 #[derive(Component)]
 pub struct Selected;
+
+/// Background colour role, re-applied when the Theme changes
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ThemedBackground(pub ThemedColor);
+
+/// Text colour role, re-applied when the Theme changes
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ThemedText(pub ThemedColor);
+
+/// Font size role, re-applied when the Theme's typography changes
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ThemedFontSize(pub TextLevel);
+
+/// Border colour role, re-applied when the Theme changes
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ThemedBorder(pub ThemedColor);
+
+/// Themed spacing roles of a container, re-applied when `Spacing::scale` changes
+#[derive(Component, Clone, Copy, Default)]
+pub struct SpacingRoles {
+    pub padding: Option<ThemeSpacing>,
+    pub margin: Option<ThemeSpacing>,
+    pub gap: Option<ThemeSpacing>,
+}
+
+impl SpacingRoles {
+    pub fn is_empty(&self) -> bool {
+        self.padding.is_none() && self.margin.is_none() && self.gap.is_none()
+    }
+}
+
+/// Single-line text clipped to `max_width` logical pixels with a trailing "…"
+///
+/// Replace the component to change the full text; `truncate_text_system`
+/// restores it and re-measures on the next layout.
+#[derive(Component)]
+pub struct TruncatedText {
+    pub full: String,
+    pub max_width: f32,
+    pub(crate) shown_chars: Option<usize>,
+}
+
+impl TruncatedText {
+    pub fn new(full: impl Into<String>, max_width: f32) -> Self {
+        Self {
+            full: full.into(),
+            max_width,
+            shown_chars: None,
+        }
+    }
+
+    /// Whether the displayed text is currently shortened
+    pub fn is_truncated(&self) -> bool {
+        self.shown_chars.is_some()
+    }
+}
+
+/// Short nudge animation toward a direction where navigation hit an edge
+#[derive(Component)]
+pub struct NavigationBump {
+    pub direction: Vec2,
+    pub timer: Timer,
+}
+
+/// Selector frame that glides to the currently focused element
+#[derive(Component)]
+pub struct FocusCursor;
+
+/// Marker for elements currently showing the accessibility focus ring
+#[derive(Component)]
+pub struct FocusRing;
+
+/// Button that behaves like the Back action: unwinds one overlay, or else one screen
+#[derive(Component)]
+pub struct BackButton;
+
+/// Axis a Scrollable container scrolls along
+///
+/// Only vertical scrolling is supported for now.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAxis {
+    #[default]
+    Vertical,
+}
+
+/// Container whose overflowing children scroll with the mouse wheel and keyboard
+///
+/// Focused descendants are scrolled into view automatically.
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[require(RelativeCursorPosition, ScrollPosition)]
+pub struct Scrollable {
+    pub axis: ScrollAxis,
+}
+
+/// Re-fires a button's action while it is held down with the pointer
+///
+/// The first fire is the normal press; repeats start after `initial_delay`
+/// seconds and continue every `interval` seconds until released or left.
+#[derive(Component, Clone, Debug)]
+#[require(RelativeCursorPosition)]
+pub struct HoldRepeat {
+    pub initial_delay: f32,
+    pub interval: f32,
+    held: Option<f32>,
+    next_fire: f32,
+}
+
+impl HoldRepeat {
+    pub fn new(initial_delay: f32, interval: f32) -> Self {
+        Self {
+            initial_delay,
+            interval,
+            held: None,
+            next_fire: initial_delay,
+        }
+    }
+
+    /// Advances the hold timer, returning true when the action should fire again
+    pub fn tick(&mut self, delta: f32) -> bool {
+        let Some(held) = self.held.as_mut() else {
+            self.held = Some(0.0);
+            self.next_fire = self.initial_delay;
+            return false;
+        };
+
+        *held += delta;
+        if *held >= self.next_fire {
+            self.next_fire += self.interval.max(f32::EPSILON);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Stops repetition until the next press
+    pub fn reset(&mut self) {
+        self.held = None;
+    }
+}
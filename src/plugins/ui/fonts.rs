@@ -0,0 +1,125 @@
+use crate::plugins::ui::styles::Theme;
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+
+/// Source of font handles for the text and button helpers
+///
+/// `AssetServer` loads by path on every call; `FontCache` returns the
+/// already resolved theme font and only loads paths it doesn't hold.
+pub trait FontProvider {
+    fn font(&self, path: &str) -> Handle<Font>;
+}
+
+impl FontProvider for AssetServer {
+    fn font(&self, path: &str) -> Handle<Font> {
+        self.load(path.to_string())
+    }
+}
+
+impl<T: FontProvider + Resource> FontProvider for Res<'_, T> {
+    fn font(&self, path: &str) -> Handle<Font> {
+        (**self).font(path)
+    }
+}
+
+/// Cached handle of the current theme font
+#[derive(Resource)]
+pub struct FontCache {
+    path: String,
+    handle: Handle<Font>,
+    asset_server: AssetServer,
+}
+
+impl FontCache {
+    pub fn new(path: &str, asset_server: &AssetServer) -> Self {
+        Self {
+            path: path.to_string(),
+            handle: asset_server.load(path.to_string()),
+            asset_server: asset_server.clone(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn handle(&self) -> Handle<Font> {
+        self.handle.clone()
+    }
+}
+
+impl FontProvider for FontCache {
+    fn font(&self, path: &str) -> Handle<Font> {
+        if path == self.path {
+            self.handle.clone()
+        } else {
+            self.asset_server.load(path.to_string())
+        }
+    }
+}
+
+impl FromWorld for FontCache {
+    fn from_world(world: &mut World) -> Self {
+        let path = world.resource::<Theme>().typography.font_path.clone();
+        let asset_server = world.resource::<AssetServer>();
+        Self::new(&path, asset_server)
+    }
+}
+
+/// Font used in place of font paths that failed to load
+///
+/// Kept apart from the Theme so switching or reloading a theme that still
+/// names a broken path keeps using the fallback.
+#[derive(Resource, Default)]
+pub struct FontFallback {
+    failed: HashSet<String>,
+    handle: Option<Handle<Font>>,
+}
+
+impl FontFallback {
+    /// Replace `path` with the fallback font from now on
+    pub fn replace(&mut self, path: &str, fallback: Handle<Font>) {
+        self.failed.insert(path.to_string());
+        self.handle = Some(fallback);
+    }
+
+    /// Fallback handle for `path`, None when the path loads normally
+    pub fn get(&self, path: &str) -> Option<Handle<Font>> {
+        self.handle
+            .as_ref()
+            .filter(|_| self.failed.contains(path))
+            .cloned()
+    }
+}
+
+/// FontCache with the FontFallback applied, for systems spawning text
+#[derive(SystemParam)]
+pub struct Fonts<'w> {
+    cache: Res<'w, FontCache>,
+    fallback: Res<'w, FontFallback>,
+}
+
+impl FontProvider for Fonts<'_> {
+    fn font(&self, path: &str) -> Handle<Font> {
+        self.fallback
+            .get(path)
+            .unwrap_or_else(|| self.cache.font(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_applies_only_to_failed_paths() {
+        let mut fallback = FontFallback::default();
+        assert_eq!(fallback.get("fonts/missing.ttf"), None);
+
+        let handle = Handle::<Font>::default();
+        fallback.replace("fonts/missing.ttf", handle.clone());
+        assert_eq!(fallback.get("fonts/missing.ttf"), Some(handle));
+        assert_eq!(fallback.get("fonts/other.ttf"), None);
+    }
+}
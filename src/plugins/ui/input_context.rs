@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+/// Which part of the game currently owns keyboard/gamepad input
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum InputContext {
+    #[default]
+    Menu,
+    Gameplay,
+    Dialog,
+}
+
+/// Stack of input contexts, the top one receives input
+///
+/// The base context follows the current screen (Menu or Gameplay); dialogs
+/// push on top so the contexts beneath are suspended until they pop.
+#[derive(Resource, Default)]
+pub struct InputContextStack {
+    base: InputContext,
+    stack: Vec<InputContext>,
+}
+
+impl InputContextStack {
+    pub fn push(&mut self, context: InputContext) {
+        self.stack.push(context);
+        debug!(
+            "InputContextStack: push {:?}, depth: {}",
+            context,
+            self.stack.len()
+        );
+    }
+
+    pub fn pop(&mut self) -> Option<InputContext> {
+        let popped = self.stack.pop();
+        if let Some(context) = popped {
+            debug!(
+                "InputContextStack: pop {:?}, depth: {}",
+                context,
+                self.stack.len()
+            );
+        }
+        popped
+    }
+
+    /// Set the context used when nothing is pushed
+    pub fn set_base(&mut self, context: InputContext) {
+        if self.base != context {
+            debug!("InputContextStack: base {:?} -> {:?}", self.base, context);
+            self.base = context;
+        }
+    }
+
+    /// The context currently receiving input
+    pub fn active(&self) -> InputContext {
+        self.stack.last().copied().unwrap_or(self.base)
+    }
+
+    pub fn is_active(&self, context: InputContext) -> bool {
+        self.active() == context
+    }
+}
+
+/// Run condition: the given input context is active
+pub fn in_input_context(context: InputContext) -> impl FnMut(Res<InputContextStack>) -> bool + Clone {
+    move |stack: Res<InputContextStack>| stack.is_active(context)
+}
+
+/// Opened system for modal overlays: suspends the contexts beneath
+pub fn push_dialog_context(mut stack: ResMut<InputContextStack>) {
+    stack.push(InputContext::Dialog);
+}
+
+/// Closed system for modal overlays, pairs with `push_dialog_context`
+pub fn pop_dialog_context(mut stack: ResMut<InputContextStack>) {
+    if stack.is_active(InputContext::Dialog) {
+        stack.pop();
+    }
+}
@@ -1,21 +1,33 @@
 mod components;
 mod systems;
 
-use crate::plugins::ui::main_menu::systems::{
-    button_interaction_system, cleanup_main_menu, setup_main_menu,
-};
+use crate::plugins::ui::main_menu::components::OnMainMenuScreen;
+use crate::plugins::ui::main_menu::systems::{button_interaction_system, setup_main_menu};
+use crate::plugins::ui::screen::{AppScreenExt, Screen};
 use crate::state::GameState;
+use bevy::ecs::schedule::ScheduleConfigs;
+use bevy::ecs::system::ScheduleSystem;
 use bevy::prelude::*;
 
+pub struct MainMenuScreen;
+
+impl Screen for MainMenuScreen {
+    type Marker = OnMainMenuScreen;
+    const STATE: GameState = GameState::MainMenu;
+
+    fn setup() -> ScheduleConfigs<ScheduleSystem> {
+        setup_main_menu.into_configs()
+    }
+
+    fn update() -> ScheduleConfigs<ScheduleSystem> {
+        button_interaction_system.into_configs()
+    }
+}
+
 pub struct MainMenuPlugin;
 
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::MainMenu), setup_main_menu)
-            .add_systems(
-                Update,
-                button_interaction_system.run_if(in_state(GameState::MainMenu)),
-            )
-            .add_systems(OnExit(GameState::MainMenu), cleanup_main_menu);
+        app.add_screen::<MainMenuScreen>();
     }
 }
@@ -1,4 +1,7 @@
-use crate::plugins::ui::button_builder::{ButtonNavigationBuilder, ButtonSize, NavigationLayout};
+use crate::plugins::ui::button_builder::{
+    ButtonNavigationBuilder, ButtonOverrides, ButtonRole, ButtonSize, NavigationLayout,
+};
+use crate::plugins::ui::fonts::Fonts;
 use crate::plugins::ui::main_menu::components::{MainMenuButtonAction, OnMainMenuScreen};
 use crate::plugins::ui::navigation::NavigationGraph;
 use crate::plugins::ui::overlays::{OverlayAction, OverlayMessage};
@@ -9,7 +12,7 @@ use bevy::prelude::*;
 
 pub fn setup_main_menu(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    fonts: Fonts,
     theme: Res<Theme>,
     mut nav_graph: ResMut<NavigationGraph>,
 ) {
@@ -28,7 +31,7 @@ pub fn setup_main_menu(
             OnMainMenuScreen,
             |parent| {
                 // 游戏标题
-                parent.spawn(text_styled("A Bevy Of Us", TextLevel::H1, &theme, &asset_server));
+                parent.spawn(text_styled("A Bevy Of Us", TextLevel::H1, &theme, &fonts));
 
                 // 创建按钮并添加到 builder
                 let play_button = crate::plugins::ui::button_builder::spawn_button_sized(
@@ -36,7 +39,7 @@ pub fn setup_main_menu(
                     "开始游戏",
                     MainMenuButtonAction::Play,
                     &theme,
-                    &asset_server,
+                    &fonts,
                     ButtonSize::Large,
                 );
                 button_builder.add_button(play_button);
@@ -46,18 +49,19 @@ pub fn setup_main_menu(
                     "设置",
                     MainMenuButtonAction::Settings,
                     &theme,
-                    &asset_server,
+                    &fonts,
                     ButtonSize::Large,
                 );
                 button_builder.add_button(settings_button);
 
-                let quit_button = crate::plugins::ui::button_builder::spawn_button_sized(
+                let quit_button = crate::plugins::ui::button_builder::spawn_styled_button(
                     parent,
                     "退出",
                     MainMenuButtonAction::Quit,
                     &theme,
-                    &asset_server,
+                    &fonts,
                     ButtonSize::Large,
+                    ButtonOverrides::new().with_role(ButtonRole::Danger),
                 );
                 button_builder.add_button(quit_button);
             },
@@ -97,13 +101,3 @@ pub fn button_interaction_system(
         }
     }
 }
-
-pub fn cleanup_main_menu(
-    mut commands: Commands,
-    main_menu_query: Query<Entity, With<OnMainMenuScreen>>,
-) {
-    // 查询所有带有 OnMainMenuScreen 标记的实体并销毁它们
-    for entity in main_menu_query.iter() {
-        commands.entity(entity).despawn();
-    }
-}
@@ -1,50 +1,299 @@
+use crate::plugins::ui::button_builder::button_visual_state_system;
+use crate::plugins::ui::fonts::{FontCache, FontFallback};
+use crate::plugins::ui::input_context::{InputContext, InputContextStack, in_input_context};
 use crate::plugins::ui::main_menu::MainMenuPlugin;
-use crate::plugins::ui::navigation::NavigationGraph;
-use crate::plugins::ui::overlays::OverlayPlugin;
-use crate::plugins::ui::resources::MenuStack;
-use crate::plugins::ui::styles::Theme;
-use crate::plugins::ui::systems::menu_stack_control_system;
+use crate::plugins::ui::navigation::{
+    ActivationKeys, NavigationBumped, NavigationConfig, NavigationEvent, NavigationGraph,
+};
+use crate::plugins::ui::overlays::{OverlayLifecycle, OverlayPlugin};
+use crate::plugins::ui::resources::{
+    CoveredNavigation, MenuBackStack, MenuStack, TargetAspect, UiAnimations,
+};
+use crate::plugins::ui::styles::{Theme, ThemeMessage, ThemeVariant};
+use crate::plugins::ui::systems::disabled::{
+    apply_disabled_style_system, restore_disabled_style_system,
+};
+use crate::plugins::ui::systems::focus_cursor::focus_cursor_system;
+use crate::plugins::ui::systems::font_check::{
+    check_theme_font_system, setup_font_check, update_font_cache_system,
+};
+use crate::plugins::ui::systems::gamepad::gamepad_connection_system;
+use crate::plugins::ui::systems::hold_repeat::hold_repeat_system;
+use crate::plugins::ui::systems::letterbox::letterbox_system;
+use crate::plugins::ui::systems::{
+    back_navigation_system, menu_stack_control_system, overlay_navigation_system,
+    record_screen_history_system, sync_input_context_system,
+};
 use crate::plugins::ui::systems::navigation::{
-    ButtonStyleResource, cleanup_despawned_buttons, handle_navigation_input,
-    universal_button_style_system,
+    ButtonStyleResource, activate_focused_button_system, cleanup_despawned_buttons,
+    emit_navigation_event_system,
+    focus_ring_system, gamepad_navigation_system, handle_navigation_input, navigation_bump_system,
+    start_navigation_bump_system,
+};
+use crate::plugins::ui::stepper::StepperChanged;
+use crate::plugins::ui::systems::scroll::{
+    scroll_focus_into_view_system, scroll_keyboard_system, scroll_wheel_system,
+};
+use crate::plugins::ui::systems::spacing::apply_spacing_scale_system;
+use crate::plugins::ui::systems::stepper::{
+    stepper_button_system, stepper_keyboard_system, update_stepper_display_system,
 };
+use crate::plugins::ui::systems::telemetry::{
+    emit_button_telemetry_system, log_button_telemetry_system,
+};
+use crate::plugins::ui::systems::text::{apply_font_scale_system, truncate_text_system};
+use crate::plugins::ui::systems::theme_reload::{apply_theme_asset_system, load_theme_asset};
+use crate::plugins::ui::systems::theme_switch::{
+    refresh_themed_elements_system, switch_theme_system,
+};
+use crate::plugins::ui::systems::toast::{spawn_toast_system, update_toast_system};
+use crate::plugins::ui::systems::tooltip::tooltip_system;
+use crate::plugins::ui::theme_asset::{ThemeAsset, ThemeAssetLoader};
+use crate::plugins::ui::telemetry::{ButtonTelemetry, ButtonTelemetryConfig};
+use crate::plugins::ui::toast::ToastMessage;
 use crate::state::OverlayState;
 use bevy::input_focus::InputDispatchPlugin;
 use bevy::input_focus::directional_navigation::DirectionalNavigationPlugin;
 use bevy::prelude::*;
+use bevy::ui::UiSystems;
 
 pub mod button_builder;
+pub mod clipboard;
 pub mod components;
+pub mod fonts;
+pub mod input_context;
 pub mod main_menu;
 pub mod navigation;
 pub mod overlays;
 mod resources;
+pub use resources::AccessibilitySettings;
+pub mod screen;
+pub mod stepper;
 pub mod styles;
 pub mod systems;
+pub mod telemetry;
+#[cfg(test)]
+pub mod testing;
+pub mod theme_asset;
+pub mod toast;
+pub mod tooltip;
 pub mod ui_builders;
 
-pub struct UiPlugin;
+/// UI插件，默认启用除聚焦光标外的全部子系统
+///
+/// 嵌入时可通过builder关闭不需要的部分：
+/// - `with_tooltips`: 悬停提示框 `tooltip_system`
+/// - `with_animations`: 导航到边缘时的回弹动画，以及聚焦光标的滑动 (关闭后直接跳到目标)
+/// - `with_gamepad`: 手柄连接提示 `gamepad_connection_system` 与十字键/摇杆导航
+/// - `with_focus_cursor`: 在聚焦元素间滑动的共享光标 `focus_cursor_system`
+/// - `with_target_aspect`: 将界面限制在固定宽高比的居中区域内，其余部分以
+///   `theme.colors.background` 填充黑边 (`letterbox_system`)，默认铺满整个窗口
+///
+/// 聚焦光标是唯一默认关闭的例外：它替代按钮自身的聚焦配色，属于需要显式选择的外观，
+/// 其余子系统与原先的单元结构体行为一致。
+///
+/// 注意：`UiPlugin` 曾是单元结构体，改为带字段的结构体后 `add_plugins(UiPlugin)`
+/// 无法再编译，需改写为 `add_plugins(UiPlugin::default())` (或 `UiPlugin::new()`)，
+/// 行为不变。
+pub struct UiPlugin {
+    pub tooltips: bool,
+    pub animations: bool,
+    pub gamepad: bool,
+    pub focus_cursor: bool,
+    pub target_aspect: Option<f32>,
+}
+
+impl Default for UiPlugin {
+    fn default() -> Self {
+        Self {
+            tooltips: true,
+            animations: true,
+            gamepad: true,
+            focus_cursor: false,
+            target_aspect: None,
+        }
+    }
+}
+
+impl UiPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tooltips(mut self, enabled: bool) -> Self {
+        self.tooltips = enabled;
+        self
+    }
+
+    pub fn with_animations(mut self, enabled: bool) -> Self {
+        self.animations = enabled;
+        self
+    }
+
+    pub fn with_gamepad(mut self, enabled: bool) -> Self {
+        self.gamepad = enabled;
+        self
+    }
+
+    pub fn with_focus_cursor(mut self, enabled: bool) -> Self {
+        self.focus_cursor = enabled;
+        self
+    }
+
+    /// 宽高比 (宽/高)，`None` 为铺满整个窗口
+    pub fn with_target_aspect(mut self, aspect: Option<f32>) -> Self {
+        self.target_aspect = aspect;
+        self
+    }
+}
+
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<OverlayState>()
             .init_resource::<MenuStack>()
+            .init_resource::<CoveredNavigation>()
+            .init_resource::<MenuBackStack>()
+            .init_resource::<InputContextStack>()
             .init_resource::<NavigationGraph>()
+            .init_resource::<NavigationConfig>()
+            .init_resource::<ActivationKeys>()
             .init_resource::<ButtonStyleResource>()
             .init_resource::<Theme>()
+            .init_resource::<ThemeVariant>()
+            .init_resource::<FontCache>()
+            .init_resource::<FontFallback>()
+            .init_resource::<AccessibilitySettings>()
+            .init_resource::<ButtonTelemetryConfig>()
+            .insert_resource(UiAnimations(self.animations))
+            .add_message::<ToastMessage>()
+            .add_message::<ButtonTelemetry>()
+            .add_message::<StepperChanged>()
+            .add_message::<NavigationBumped>()
+            .add_message::<NavigationEvent>()
+            .add_message::<ThemeMessage>()
             .add_plugins(InputDispatchPlugin)
             .add_plugins(DirectionalNavigationPlugin)
             .add_plugins(OverlayPlugin)
             .add_plugins(MainMenuPlugin)
-            .add_systems(Startup, setup_theme_resources)
-            .add_systems(Update, menu_stack_control_system)
+            .init_asset::<ThemeAsset>()
+            .init_asset_loader::<ThemeAssetLoader>()
+            .add_systems(
+                Startup,
+                (setup_theme_resources, setup_font_check, load_theme_asset),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_theme_asset_system,
+                    switch_theme_system,
+                    refresh_themed_elements_system,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    record_screen_history_system,
+                    // 游戏中和对话框里不响应 Back
+                    back_navigation_system.run_if(in_input_context(InputContext::Menu)),
+                    menu_stack_control_system,
+                    overlay_navigation_system,
+                    sync_input_context_system,
+                )
+                    .chain(),
+            )
+            .configure_sets(Update, OverlayLifecycle.after(overlay_navigation_system))
+            .add_systems(
+                PreUpdate,
+                activate_focused_button_system
+                    .after(UiSystems::Focus)
+                    .run_if(not(in_input_context(InputContext::Gameplay))),
+            )
+            .add_systems(PostUpdate, letterbox_system)
             .add_systems(
                 Update,
                 (
-                    handle_navigation_input,
-                    universal_button_style_system,
+                    (
+                        handle_navigation_input
+                            .run_if(not(in_input_context(InputContext::Gameplay))),
+                        emit_navigation_event_system,
+                    )
+                        .chain(),
+                    (
+                        apply_disabled_style_system,
+                        restore_disabled_style_system,
+                        button_visual_state_system,
+                    )
+                        .chain(),
+                    focus_ring_system,
+                    hold_repeat_system,
+                    apply_spacing_scale_system,
+                    apply_font_scale_system,
+                    truncate_text_system,
+                    scroll_wheel_system,
+                    scroll_keyboard_system.run_if(not(in_input_context(InputContext::Gameplay))),
+                    scroll_focus_into_view_system,
                     cleanup_despawned_buttons,
                 ),
+            )
+            .add_systems(
+                Update,
+                (
+                    check_theme_font_system,
+                    update_font_cache_system,
+                    spawn_toast_system,
+                    update_toast_system,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (emit_button_telemetry_system, log_button_telemetry_system).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    stepper_button_system,
+                    stepper_keyboard_system.run_if(not(in_input_context(InputContext::Gameplay))),
+                    update_stepper_display_system,
+                )
+                    .chain(),
+            );
+
+        if self.tooltips {
+            app.add_systems(Update, tooltip_system);
+        }
+
+        if self.animations {
+            app.add_systems(
+                Update,
+                (start_navigation_bump_system, navigation_bump_system)
+                    .chain()
+                    .after(handle_navigation_input)
+                    .after(gamepad_navigation_system)
+                    .before(emit_navigation_event_system),
+            );
+        }
+
+        if self.focus_cursor {
+            app.add_systems(Update, focus_cursor_system);
+        }
+
+        if let Some(aspect) = self.target_aspect {
+            app.insert_resource(TargetAspect(aspect));
+        }
+
+        if self.gamepad {
+            app.add_systems(
+                Update,
+                (
+                    gamepad_connection_system.before(spawn_toast_system),
+                    gamepad_navigation_system
+                        .run_if(not(in_input_context(InputContext::Gameplay)))
+                        .before(emit_navigation_event_system),
+                ),
             );
+        }
     }
 }
 
@@ -9,6 +9,86 @@ pub enum Direction {
     Right,
 }
 
+impl Direction {
+    /// Unit vector in UI space (y grows downwards)
+    pub fn as_vec2(&self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::NEG_Y,
+            Direction::Down => Vec2::Y,
+            Direction::Left => Vec2::NEG_X,
+            Direction::Right => Vec2::X,
+        }
+    }
+}
+
+/// Message emitted when navigation is requested past an edge with no neighbor
+#[derive(Message, Clone, Copy, Debug)]
+pub struct NavigationBumped {
+    pub entity: Entity,
+    pub direction: Direction,
+}
+
+/// Message emitted whenever the focused entity changes
+///
+/// `from` is None when nothing was focused before. Setting focus to the
+/// entity that already has it emits nothing.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct NavigationEvent {
+    pub from: Option<Entity>,
+    pub to: Entity,
+}
+
+/// Timing and thresholds for gamepad navigation
+#[derive(Resource, Clone, Debug)]
+pub struct NavigationConfig {
+    /// Left stick deflection (0..1) needed to count as a direction
+    pub stick_threshold: f32,
+    /// Seconds a direction must be held before it starts repeating
+    pub repeat_delay: f32,
+    /// Seconds between repeats while the direction stays held
+    pub repeat_interval: f32,
+}
+
+impl Default for NavigationConfig {
+    fn default() -> Self {
+        Self {
+            stick_threshold: 0.5,
+            repeat_delay: 0.4,
+            repeat_interval: 0.15,
+        }
+    }
+}
+
+/// Keys and gamepad buttons that press the focused button
+#[derive(Resource, Clone, Debug)]
+pub struct ActivationKeys {
+    pub keys: Vec<KeyCode>,
+    pub gamepad_buttons: Vec<GamepadButton>,
+}
+
+impl Default for ActivationKeys {
+    fn default() -> Self {
+        Self {
+            keys: vec![KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space],
+            gamepad_buttons: vec![GamepadButton::South],
+        }
+    }
+}
+
+impl ActivationKeys {
+    /// Whether any activation input was pressed this frame
+    pub fn just_pressed<'a>(
+        &self,
+        keyboard: &ButtonInput<KeyCode>,
+        gamepads: impl IntoIterator<Item = &'a Gamepad>,
+    ) -> bool {
+        keyboard.any_just_pressed(self.keys.iter().copied())
+            || gamepads.into_iter().any(|gamepad| {
+                gamepad.any_just_pressed(self.gamepad_buttons.iter().copied())
+            })
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct NavigationNeighbors {
     pub up: Option<Entity>,
@@ -41,6 +121,26 @@ impl NavigationNeighbors {
         self.right = entity;
         self
     }
+
+    /// Get the neighbor in a specific direction
+    pub fn get(&self, direction: Direction) -> Option<Entity> {
+        match direction {
+            Direction::Up => self.up,
+            Direction::Down => self.down,
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+        }
+    }
+
+    /// Set the neighbor in a specific direction
+    pub fn set(&mut self, direction: Direction, entity: Option<Entity>) {
+        match direction {
+            Direction::Up => self.up = entity,
+            Direction::Down => self.down = entity,
+            Direction::Left => self.left = entity,
+            Direction::Right => self.right = entity,
+        }
+    }
 }
 
 /// Resource to track navigation relationships between buttons
@@ -66,12 +166,30 @@ impl NavigationGraph {
     pub fn get_neighbor(&self, entity: Entity, direction: Direction) -> Option<Entity> {
         self.relationships
             .get(&entity)
-            .and_then(|neighbors| match direction {
-                Direction::Up => neighbors.up,
-                Direction::Down => neighbors.down,
-                Direction::Left => neighbors.left,
-                Direction::Right => neighbors.right,
-            })
+            .and_then(|neighbors| neighbors.get(direction))
+    }
+
+    /// Get all neighbors of a registered button
+    pub fn neighbors(&self, entity: Entity) -> Option<&NavigationNeighbors> {
+        self.relationships.get(&entity)
+    }
+
+    /// Patch a single neighbor of a registered button
+    ///
+    /// Returns false if the button is not registered.
+    pub fn set_neighbor(
+        &mut self,
+        entity: Entity,
+        direction: Direction,
+        target: Option<Entity>,
+    ) -> bool {
+        match self.relationships.get_mut(&entity) {
+            Some(neighbors) => {
+                neighbors.set(direction, target);
+                true
+            }
+            None => false,
+        }
     }
 
     /// Set the currently focused button
@@ -89,9 +207,42 @@ impl NavigationGraph {
         self.focused_button
     }
 
-    /// Remove a button from the graph
+    /// Remove a button from the graph, linking its former neighbors to each other
+    ///
+    /// E.g. removing the middle of A-B-C in a vertical list leaves A-C.
     pub fn remove_button(&mut self, entity: Entity) {
-        self.relationships.remove(&entity);
+        if let Some(removed) = self.relationships.remove(&entity) {
+            let opposite_pairs = [
+                (Direction::Up, Direction::Down),
+                (Direction::Down, Direction::Up),
+                (Direction::Left, Direction::Right),
+                (Direction::Right, Direction::Left),
+            ];
+            for (towards, back) in opposite_pairs {
+                let Some(from) = removed.get(towards) else {
+                    continue;
+                };
+                if self.get_neighbor(from, back) == Some(entity) {
+                    let replacement = removed.get(back).filter(|&target| target != from);
+                    self.set_neighbor(from, back, replacement);
+                }
+            }
+        }
+
+        // Drop any remaining links to the removed button
+        for neighbors in self.relationships.values_mut() {
+            for direction in [
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ] {
+                if neighbors.get(direction) == Some(entity) {
+                    neighbors.set(direction, None);
+                }
+            }
+        }
+
         if self.focused_button == Some(entity) {
             self.focused_button = None;
         }
@@ -103,3 +254,32 @@ impl NavigationGraph {
         self.focused_button = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removing_middle_button_links_its_neighbours() {
+        let mut world = World::new();
+        let [a, b, c] = [(); 3].map(|_| world.spawn_empty().id());
+
+        let mut graph = NavigationGraph::new();
+        graph.register_button(a, NavigationNeighbors::new().with_down(Some(b)));
+        graph.register_button(
+            b,
+            NavigationNeighbors::new()
+                .with_up(Some(a))
+                .with_down(Some(c)),
+        );
+        graph.register_button(c, NavigationNeighbors::new().with_up(Some(b)));
+        graph.set_focus(b);
+
+        graph.remove_button(b);
+
+        assert!(graph.neighbors(b).is_none());
+        assert_eq!(graph.get_neighbor(a, Direction::Down), Some(c));
+        assert_eq!(graph.get_neighbor(c, Direction::Up), Some(a));
+        assert_eq!(graph.get_focused(), None);
+    }
+}
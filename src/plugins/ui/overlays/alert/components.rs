@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+#[derive(Component)]
+pub struct OnAlertScreen;
+
+#[derive(Component)]
+pub enum AlertButtonAction {
+    Ok,
+}
+
+/// 单条提示框内容
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub title: String,
+    pub body: String,
+}
+
+/// 请求弹出只有"确定"按钮的提示框
+///
+/// 已有提示框显示时进入队列，关闭后依次弹出，不会叠加
+#[derive(Message)]
+pub struct AlertMessage {
+    pub alert: Alert,
+}
+
+impl AlertMessage {
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            alert: Alert {
+                title: title.into(),
+                body: body.into(),
+            },
+        }
+    }
+}
+
+/// 提示框队列，`active` 为当前显示的提示框
+#[derive(Resource, Default)]
+pub struct AlertQueue {
+    pub(super) pending: VecDeque<Alert>,
+    pub(super) active: Option<Alert>,
+}
+
+impl AlertQueue {
+    pub fn len(&self) -> usize {
+        self.pending.len() + usize::from(self.active.is_some())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,39 @@
+mod components;
+mod systems;
+
+pub use components::{AlertMessage, AlertQueue};
+
+use crate::plugins::ui::input_context::{pop_dialog_context, push_dialog_context};
+use crate::plugins::ui::overlays::alert::systems::{
+    alert_button_interaction_system, alert_escape_system, cleanup_alert_ui, queue_alert_system,
+    setup_alert_ui,
+};
+use crate::plugins::ui::overlays::{OverlayChange, OverlayLifecycle, overlay_changed};
+use crate::state::OverlayState;
+use bevy::prelude::*;
+
+pub struct AlertPlugin;
+
+impl Plugin for AlertPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AlertQueue>()
+            .add_message::<AlertMessage>()
+            .add_systems(
+                Update,
+                (
+                    (cleanup_alert_ui, pop_dialog_context)
+                        .run_if(overlay_changed(OverlayState::Alert, OverlayChange::Closed)),
+                    (setup_alert_ui, push_dialog_context)
+                        .run_if(overlay_changed(OverlayState::Alert, OverlayChange::Opened)),
+                )
+                    .chain()
+                    .in_set(OverlayLifecycle),
+            )
+            .add_systems(Update, queue_alert_system)
+            .add_systems(
+                Update,
+                (alert_button_interaction_system, alert_escape_system)
+                    .run_if(in_state(OverlayState::Alert)),
+            );
+    }
+}
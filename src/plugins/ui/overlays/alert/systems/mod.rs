@@ -0,0 +1,166 @@
+use crate::plugins::core::{GlobalAction, GlobalInputController};
+use crate::plugins::ui::button_builder::{
+    ButtonNavigationBuilder, ButtonSize, NavigationLayout, spawn_button_sized,
+};
+use crate::plugins::ui::fonts::Fonts;
+use crate::plugins::ui::navigation::NavigationGraph;
+use crate::plugins::ui::resources::MenuStack;
+use crate::plugins::ui::overlays::alert::components::{
+    AlertButtonAction, AlertMessage, AlertQueue, OnAlertScreen,
+};
+use crate::plugins::ui::overlays::{ModalOverlay, OverlayAction, OverlayMessage};
+use crate::plugins::ui::styles::{Theme, ThemeSpacing};
+use crate::plugins::ui::ui_builders::{TextLevel, text_styled};
+use crate::state::OverlayState;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+
+/// System that queues incoming alerts and opens the next one when none is shown
+pub fn queue_alert_system(
+    mut reader: MessageReader<AlertMessage>,
+    mut alert_queue: ResMut<AlertQueue>,
+    mut mw_overlay: MessageWriter<OverlayMessage>,
+) {
+    for message in reader.read() {
+        alert_queue.pending.push_back(message.alert.clone());
+    }
+
+    if alert_queue.active.is_none()
+        && let Some(alert) = alert_queue.pending.pop_front()
+    {
+        info!("Showing alert: {}", alert.title);
+        alert_queue.active = Some(alert);
+        mw_overlay.write(OverlayMessage {
+            action: OverlayAction::Push,
+            overlay: OverlayState::Alert,
+        });
+    }
+}
+
+pub fn setup_alert_ui(
+    mut commands: Commands,
+    fonts: Fonts,
+    theme: Res<Theme>,
+    alert_queue: Res<AlertQueue>,
+    menu_stack: Res<MenuStack>,
+    mut nav_graph: ResMut<NavigationGraph>,
+) {
+    let Some(alert) = alert_queue.active.clone() else {
+        warn!("Entered alert overlay without an active alert");
+        return;
+    };
+
+    nav_graph.clear();
+    let mut button_builder = ButtonNavigationBuilder::new(NavigationLayout::Vertical);
+
+    // 每层浮层比下一层高一级
+    let close_button = ModalOverlay::new(&theme, menu_stack.z_index())
+        .gap(ThemeSpacing::MD)
+        .spawn(&mut commands, &fonts, OnAlertScreen, |card| {
+            card.spawn(text_styled(alert.title, TextLevel::H2, &theme, &fonts));
+            card.spawn(text_styled(
+                alert.body,
+                TextLevel::Body,
+                &theme,
+                &fonts,
+            ));
+
+            let ok_button = spawn_button_sized(
+                card,
+                "确定",
+                AlertButtonAction::Ok,
+                &theme,
+                &fonts,
+                ButtonSize::Medium,
+            );
+            button_builder.add_button(ok_button);
+        });
+    if let Some(close_button) = close_button {
+        button_builder.add_button(close_button);
+    }
+
+    // 默认聚焦"确定"按钮
+    button_builder.build(&mut commands, &mut nav_graph, true);
+}
+
+pub fn alert_button_interaction_system(
+    q_interaction: Query<(&Interaction, &AlertButtonAction), (Changed<Interaction>, With<Button>)>,
+    mut mw_overlay: MessageWriter<OverlayMessage>,
+) {
+    for (interaction, action) in &q_interaction {
+        if *interaction == Interaction::Pressed {
+            match action {
+                AlertButtonAction::Ok => {
+                    info!("Alert dismissed");
+                    mw_overlay.write(OverlayMessage {
+                        action: OverlayAction::Pop,
+                        overlay: OverlayState::Alert,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Escape 关闭提示框
+///
+/// 提示框打开时处于 `InputContext::Dialog`，全局的 Escape 处理不会再弹出下层浮层，
+/// 因此只关闭提示框这一层
+pub fn alert_escape_system(
+    q_action: Query<&ActionState<GlobalAction>, With<GlobalInputController>>,
+    mut mw_overlay: MessageWriter<OverlayMessage>,
+) {
+    if let Ok(action_state) = q_action.single()
+        && action_state.just_pressed(&GlobalAction::ToggleMenu)
+    {
+        info!("Alert dismissed with Escape");
+        mw_overlay.write(OverlayMessage {
+            action: OverlayAction::Pop,
+            overlay: OverlayState::Alert,
+        });
+    }
+}
+
+pub fn cleanup_alert_ui(
+    mut commands: Commands,
+    alert_query: Query<Entity, With<OnAlertScreen>>,
+    mut alert_queue: ResMut<AlertQueue>,
+) {
+    for entity in alert_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    // 关闭后允许队列中的下一条提示弹出
+    alert_queue.active = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::ui::input_context::{InputContext, InputContextStack};
+    use crate::plugins::ui::systems::menu_stack_control_system;
+    use crate::plugins::ui::testing::{minimal_ui_app, press_global, spawn_global_controller};
+
+    #[test]
+    fn escape_in_dialog_closes_only_the_alert() {
+        let mut app = minimal_ui_app();
+        app.add_systems(
+            Update,
+            (alert_escape_system, menu_stack_control_system).chain(),
+        );
+        let controller = spawn_global_controller(&mut app);
+
+        let mut menu_stack = app.world_mut().resource_mut::<MenuStack>();
+        menu_stack.push(OverlayState::Settings);
+        menu_stack.push(OverlayState::Alert);
+        app.world_mut()
+            .resource_mut::<InputContextStack>()
+            .push(InputContext::Dialog);
+
+        press_global(&mut app, controller, GlobalAction::ToggleMenu);
+        app.update();
+
+        let menu_stack = app.world().resource::<MenuStack>();
+        assert_eq!(menu_stack.depth(), 1);
+        assert_eq!(menu_stack.peek(), Some(&OverlayState::Settings));
+    }
+}
@@ -2,3 +2,7 @@ use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct OverlayBackgroundMarker;
+
+/// Action of the × button that closes the topmost overlay
+#[derive(Component)]
+pub struct OverlayCloseButton;
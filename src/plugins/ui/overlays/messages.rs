@@ -2,8 +2,12 @@ use crate::state::OverlayState;
 use bevy::prelude::*;
 
 pub enum OverlayAction {
+    /// Open an overlay on top of the current one
     Push,
+    /// Close the top overlay, revealing the one beneath
     Pop,
+    /// Close the top overlay and open another in its place
+    Replace,
 }
 
 #[derive(Message)]
@@ -11,3 +15,27 @@ pub struct OverlayMessage {
     pub action: OverlayAction,
     pub overlay: OverlayState,
 }
+
+/// What a MenuStack change did to one overlay
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverlayChange {
+    /// Added to the stack: spawn its UI
+    Opened,
+    /// Removed from the stack: despawn its UI
+    Closed,
+    /// Another overlay was opened on top: stays spawned but loses focus
+    Covered,
+    /// The overlay above it closed: it is on top again
+    Revealed,
+}
+
+/// Message written by `menu_stack_control_system` for every overlay affected
+/// by a Push, Pop or Replace
+///
+/// `OverlayState::None` stands for the screen beneath all overlays, which is
+/// covered by the first overlay and revealed when the last one closes.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct OverlayChanged {
+    pub overlay: OverlayState,
+    pub change: OverlayChange,
+}
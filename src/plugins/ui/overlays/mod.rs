@@ -1,19 +1,54 @@
+use crate::plugins::ui::button_builder::{ButtonOverrides, ButtonRole, ButtonSize, spawn_styled_button};
+use crate::plugins::ui::fonts::FontProvider;
+use crate::plugins::ui::overlays::alert::AlertPlugin;
 use crate::plugins::ui::overlays::settings::SettingsPlugin;
+use crate::plugins::ui::resources::{MenuStack, OVERLAY_BASE_Z_INDEX};
+use crate::plugins::ui::styles::{Theme, ThemeSpacing};
+use crate::plugins::ui::ui_builders::ContainerBuilder;
+use crate::state::OverlayState;
+use bevy::ecs::relationship::RelatedSpawnerCommands;
 use bevy::prelude::*;
 
+pub mod alert;
 mod components;
-pub use components::OverlayBackgroundMarker;
+pub use components::{OverlayBackgroundMarker, OverlayCloseButton};
 mod messages;
 pub mod settings;
-pub use messages::{OverlayAction, OverlayMessage};
+pub use messages::{OverlayAction, OverlayChange, OverlayChanged, OverlayMessage};
+
+/// Systems that spawn and despawn overlay UI in response to OverlayChanged
+///
+/// Runs after `overlay_navigation_system`, in the frame the MenuStack changed.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OverlayLifecycle;
 
 pub struct OverlayPlugin;
 impl Plugin for OverlayPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<OverlayMessage>()
-            .add_plugins(SettingsPlugin);
+            .add_message::<OverlayChanged>()
+            .add_plugins((SettingsPlugin, AlertPlugin))
+            .add_systems(Update, overlay_close_button_system);
+    }
+}
+
+/// Run condition: `overlay` went through `change` since the condition last ran
+///
+/// Overlays stay spawned while covered, so their setup and cleanup run on
+/// `Opened`/`Closed` rather than on entering or leaving `OverlayState`.
+pub fn overlay_changed(
+    overlay: OverlayState,
+    change: OverlayChange,
+) -> impl FnMut(MessageReader<OverlayChanged>) -> bool + Clone {
+    move |mut reader: MessageReader<OverlayChanged>| {
+        reader
+            .read()
+            .filter(|message| message.overlay == overlay && message.change == change)
+            .count()
+            > 0
     }
 }
+
 pub fn setup_overlay_background(commands: &mut Commands) {
     commands.spawn((
         Node {
@@ -22,16 +57,129 @@ pub fn setup_overlay_background(commands: &mut Commands) {
             ..default()
         },
         BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
-        ZIndex(1),
+        ZIndex(OVERLAY_BASE_Z_INDEX),
         OverlayBackgroundMarker,
     ));
 }
 
 pub fn cleanup_overlay_background(
     commands: &mut Commands,
-    query: Query<Entity, With<OverlayBackgroundMarker>>,
+    query: &Query<Entity, With<OverlayBackgroundMarker>>,
 ) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
     }
 }
+
+/// 模态浮层：全屏根节点加居中卡片，卡片右上角默认带关闭(×)按钮
+///
+/// 浮层统一通过它生成，关闭按钮自动加入；必须做出选择的浮层可用
+/// `close_button(false)` 关闭
+pub struct ModalOverlay<'a> {
+    theme: &'a Theme,
+    z_index: ZIndex,
+    gap: ThemeSpacing,
+    close_button: bool,
+}
+
+impl<'a> ModalOverlay<'a> {
+    /// `z_index` 通常取 `MenuStack::z_index`，使浮层位于下层浮层之上
+    pub fn new(theme: &'a Theme, z_index: ZIndex) -> Self {
+        Self {
+            theme,
+            z_index,
+            gap: ThemeSpacing::SM,
+            close_button: true,
+        }
+    }
+
+    /// 卡片内子元素的间距，默认 `SM`
+    pub fn gap(mut self, gap: ThemeSpacing) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// 是否生成关闭(×)按钮，默认生成
+    pub fn close_button(mut self, enabled: bool) -> Self {
+        self.close_button = enabled;
+        self
+    }
+
+    /// 生成浮层，`bundle` 加在根节点上 (通常是浮层的清理标记)
+    ///
+    /// 返回关闭按钮的Entity，调用方需将其加入导航；未生成时返回None
+    pub fn spawn<B: Bundle>(
+        self,
+        commands: &mut Commands,
+        asset_server: &impl FontProvider,
+        bundle: B,
+        build_children: impl FnOnce(&mut RelatedSpawnerCommands<ChildOf>),
+    ) -> Option<Entity> {
+        let theme = self.theme;
+        let mut close_button = None;
+        ContainerBuilder::root().spawn_with(commands, (bundle, self.z_index), |root| {
+            ContainerBuilder::card(theme)
+                .centered()
+                .gap_themed(self.gap, theme)
+                .spawn_in(root, (), |card| {
+                    build_children(card);
+                    if self.close_button {
+                        close_button = Some(spawn_close_button(card, theme, asset_server));
+                    }
+                });
+        });
+        close_button
+    }
+}
+
+/// 在浮层右上角生成关闭(×)按钮，返回按钮Entity以便加入导航
+///
+/// 父节点需要是浮层的卡片容器，按钮使用绝对定位；悬停时使用 `error` 颜色提示。
+/// `ModalOverlay` 会自动调用，一般不需要手动使用
+pub fn spawn_close_button(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    theme: &Theme,
+    asset_server: &impl FontProvider,
+) -> Entity {
+    let size = theme.spacing.xl + theme.spacing.sm;
+
+    let mut close_button = Entity::PLACEHOLDER;
+    parent
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top: px(theme.spacing.md),
+            right: px(theme.spacing.md),
+            ..default()
+        })
+        .with_children(|corner| {
+            close_button = spawn_styled_button(
+                corner,
+                "×",
+                OverlayCloseButton,
+                theme,
+                asset_server,
+                ButtonSize::Custom(size, size),
+                ButtonOverrides::new().with_role(ButtonRole::Close),
+            );
+        });
+    close_button
+}
+
+/// 关闭按钮被按下时弹出最上层浮层
+pub fn overlay_close_button_system(
+    q_interaction: Query<&Interaction, (Changed<Interaction>, With<OverlayCloseButton>)>,
+    menu_stack: Res<MenuStack>,
+    mut mw_overlay: MessageWriter<OverlayMessage>,
+) {
+    for interaction in &q_interaction {
+        if *interaction == Interaction::Pressed
+            && let Some(&overlay) = menu_stack.peek()
+        {
+            info!("Close button clicked, closing {:?}", overlay);
+            mw_overlay.write(OverlayMessage {
+                action: OverlayAction::Pop,
+                overlay,
+            });
+        }
+    }
+}
@@ -7,8 +7,15 @@ pub struct OnSettingsScreen;
 pub enum SettingsButtonAction {
     SelectResolution(usize), // Index into available_resolutions
     ToggleWindowMode,
-    Back,
+    ToggleVsync,
+    CycleFrameCap,
+    ExportSettings,
+    ImportSettings,
+    ToggleTheme,
 }
 
 #[derive(Component)]
 pub struct WindowModeLabel;
+
+#[derive(Component)]
+pub struct FrameSettingsLabel;
@@ -2,9 +2,11 @@ mod components;
 mod systems;
 
 use crate::plugins::ui::overlays::settings::systems::{
-    cleanup_settings_ui, settings_button_interaction_system, setup_settings_ui,
-    update_window_mode_label_system,
+    cleanup_settings_ui, import_settings_system, settings_button_interaction_system,
+    setup_settings_ui,
+    update_frame_settings_label_system, update_window_mode_label_system,
 };
+use crate::plugins::ui::overlays::{OverlayChange, OverlayLifecycle, overlay_changed};
 use crate::state::OverlayState;
 use bevy::prelude::*;
 
@@ -12,15 +14,30 @@ pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(OverlayState::Settings), setup_settings_ui)
-            .add_systems(
-                Update,
-                (
-                    settings_button_interaction_system,
-                    update_window_mode_label_system,
-                )
-                    .run_if(in_state(OverlayState::Settings)),
+        app.add_systems(
+            Update,
+            (
+                cleanup_settings_ui.run_if(overlay_changed(
+                    OverlayState::Settings,
+                    OverlayChange::Closed,
+                )),
+                setup_settings_ui.run_if(overlay_changed(
+                    OverlayState::Settings,
+                    OverlayChange::Opened,
+                )),
             )
-            .add_systems(OnExit(OverlayState::Settings), cleanup_settings_ui);
+                .chain()
+                .in_set(OverlayLifecycle),
+        )
+        .add_systems(
+            Update,
+            (
+                settings_button_interaction_system,
+                update_window_mode_label_system,
+                update_frame_settings_label_system,
+            )
+                .run_if(in_state(OverlayState::Settings)),
+        )
+        .add_systems(Update, import_settings_system);
     }
 }
@@ -1,18 +1,23 @@
-use crate::plugins::core::messages::ApplyDisplaySettingsMessage;
-use crate::plugins::core::resources::{DisplaySettings, Resolution};
+use crate::plugins::core::messages::{
+    ApplyDisplaySettingsMessage, ApplyFrameSettingsMessage, ImportSettingsMessage,
+};
+use crate::plugins::core::resources::{DisplaySettings, Resolution, SharedSettings};
 use crate::plugins::ui::button_builder::{
     spawn_button_sized, spawn_themed_button, ButtonNavigationBuilder, ButtonSize,
     NavigationLayout,
 };
-use crate::plugins::ui::components::Selected;
+use crate::plugins::ui::clipboard;
+use crate::plugins::ui::components::{BackButton, Selected};
+use crate::plugins::ui::fonts::Fonts;
 use crate::plugins::ui::navigation::NavigationGraph;
+use crate::plugins::ui::resources::MenuStack;
 use crate::plugins::ui::overlays::settings::components::{
-    OnSettingsScreen, SettingsButtonAction, WindowModeLabel,
+    FrameSettingsLabel, OnSettingsScreen, SettingsButtonAction, WindowModeLabel,
 };
-use crate::plugins::ui::overlays::{OverlayAction, OverlayMessage};
-use crate::plugins::ui::styles::{Theme, ThemeSpacing};
-use crate::plugins::ui::ui_builders::{spacer_themed, text_styled, ContainerBuilder, TextLevel};
-use crate::state::OverlayState;
+use crate::plugins::ui::overlays::ModalOverlay;
+use crate::plugins::ui::styles::{Theme, ThemeMessage, ThemeSpacing, ThemeVariant};
+use crate::plugins::ui::toast::ToastMessage;
+use crate::plugins::ui::ui_builders::{spacer_themed, text_styled, TextLevel};
 use bevy::prelude::*;
 use bevy::window::{MonitorSelection, WindowMode};
 
@@ -24,6 +29,14 @@ fn window_mode_to_chinese(mode: WindowMode) -> &'static str {
     }
 }
 
+fn frame_settings_text(settings: &DisplaySettings) -> String {
+    format!(
+        "垂直同步: {}  帧率上限: {}",
+        if settings.vsync { "开" } else { "关" },
+        settings.frame_cap
+    )
+}
+
 fn get_resolution_by_flat_index(
     settings: &DisplaySettings,
     mut flat_index: usize,
@@ -40,9 +53,10 @@ fn get_resolution_by_flat_index(
 
 pub fn setup_settings_ui(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    fonts: Fonts,
     theme: Res<Theme>,
     display_settings: Res<DisplaySettings>,
+    menu_stack: Res<MenuStack>,
     mut nav_graph: ResMut<NavigationGraph>,
 ) {
     let mut flat_resolutions: Vec<Resolution> = Vec::new();
@@ -61,12 +75,14 @@ pub fn setup_settings_ui(
     // Track which resolution buttons should be marked as selected
     let mut selected_buttons = Vec::new();
 
-    // Root node
-    ContainerBuilder::root()
-        .gap_themed(ThemeSpacing::SM, &theme)
-        .spawn_with(&mut commands, OnSettingsScreen, |parent| {
+    // Root node, one z-level above the overlay beneath it
+    let close_button = ModalOverlay::new(&theme, menu_stack.z_index()).spawn(
+        &mut commands,
+        &fonts,
+        OnSettingsScreen,
+        |parent| {
             // Title
-            parent.spawn(text_styled("设置", TextLevel::H1, &theme, &asset_server));
+            parent.spawn(text_styled("设置", TextLevel::H1, &theme, &fonts));
 
             // Spacing
             parent.spawn(spacer_themed(&theme, ThemeSpacing::MD));
@@ -76,7 +92,7 @@ pub fn setup_settings_ui(
                 "分辨率：",
                 TextLevel::H3,
                 &theme,
-                &asset_server,
+                &fonts,
             ));
 
             // Resolution buttons
@@ -88,7 +104,7 @@ pub fn setup_settings_ui(
                     &resolution.to_string(),
                     SettingsButtonAction::SelectResolution(index),
                     &theme,
-                    &asset_server,
+                    &fonts,
                     250.0,
                     50.0,
                 );
@@ -113,7 +129,7 @@ pub fn setup_settings_ui(
                     ),
                     TextLevel::H3,
                     &theme,
-                    &asset_server,
+                    &fonts,
                 ),
                 WindowModeLabel,
             ));
@@ -123,7 +139,7 @@ pub fn setup_settings_ui(
                 "切换窗口模式",
                 SettingsButtonAction::ToggleWindowMode,
                 &theme,
-                &asset_server,
+                &fonts,
                 ButtonSize::Medium,
             );
             button_builder.add_button(toggle_button);
@@ -131,17 +147,88 @@ pub fn setup_settings_ui(
             // Spacing
             parent.spawn(spacer_themed(&theme, ThemeSpacing::MD));
 
+            // Frame pacing section
+            parent.spawn((
+                text_styled(
+                    frame_settings_text(&display_settings),
+                    TextLevel::H3,
+                    &theme,
+                    &fonts,
+                ),
+                FrameSettingsLabel,
+            ));
+
+            let vsync_button = spawn_button_sized(
+                parent,
+                "切换垂直同步",
+                SettingsButtonAction::ToggleVsync,
+                &theme,
+                &fonts,
+                ButtonSize::Medium,
+            );
+            button_builder.add_button(vsync_button);
+
+            let frame_cap_button = spawn_button_sized(
+                parent,
+                "切换帧率上限",
+                SettingsButtonAction::CycleFrameCap,
+                &theme,
+                &fonts,
+                ButtonSize::Medium,
+            );
+            button_builder.add_button(frame_cap_button);
+
+            // Spacing
+            parent.spawn(spacer_themed(&theme, ThemeSpacing::MD));
+
+            let export_button = spawn_button_sized(
+                parent,
+                "复制设置",
+                SettingsButtonAction::ExportSettings,
+                &theme,
+                &fonts,
+                ButtonSize::Medium,
+            );
+            button_builder.add_button(export_button);
+
+            let import_button = spawn_button_sized(
+                parent,
+                "粘贴设置",
+                SettingsButtonAction::ImportSettings,
+                &theme,
+                &fonts,
+                ButtonSize::Medium,
+            );
+            button_builder.add_button(import_button);
+
+            let theme_button = spawn_button_sized(
+                parent,
+                "切换主题",
+                SettingsButtonAction::ToggleTheme,
+                &theme,
+                &fonts,
+                ButtonSize::Medium,
+            );
+            button_builder.add_button(theme_button);
+
+            // Spacing
+            parent.spawn(spacer_themed(&theme, ThemeSpacing::MD));
+
             // Back button
             let back_button = spawn_button_sized(
                 parent,
                 "返回",
-                SettingsButtonAction::Back,
+                BackButton,
                 &theme,
-                &asset_server,
+                &fonts,
                 ButtonSize::Medium,
             );
             button_builder.add_button(back_button);
-        });
+        },
+    );
+    if let Some(close_button) = close_button {
+        button_builder.add_button(close_button);
+    }
 
     // Mark selected buttons (outside the closure)
     for entity in selected_buttons {
@@ -169,6 +256,22 @@ pub fn update_window_mode_label_system(
         *text = Text::new(new_text.clone());
     }
 }
+pub fn update_frame_settings_label_system(
+    display_settings: Res<DisplaySettings>,
+    mut query: Query<&mut Text, With<FrameSettingsLabel>>,
+) {
+    if !display_settings.is_changed() {
+        return;
+    }
+
+    let new_text = frame_settings_text(&display_settings);
+
+    for mut text in query.iter_mut() {
+        *text = Text::new(new_text.clone());
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn settings_button_interaction_system(
     mut commands: Commands,
     q_interaction: Query<
@@ -176,9 +279,13 @@ pub fn settings_button_interaction_system(
         (Changed<Interaction>, With<Button>),
     >,
     q_all_res_buttons: Query<(Entity, &SettingsButtonAction), With<Button>>,
-    mut mw_overlay: MessageWriter<OverlayMessage>,
     mut apply_settings_writer: MessageWriter<ApplyDisplaySettingsMessage>,
+    mut apply_frame_writer: MessageWriter<ApplyFrameSettingsMessage>,
+    mut mw_toast: MessageWriter<ToastMessage>,
+    mut mw_theme: MessageWriter<ThemeMessage>,
+    mut mw_import: MessageWriter<ImportSettingsMessage>,
     display_settings: Res<DisplaySettings>,
+    theme_variant: Res<ThemeVariant>,
 ) {
     for (entity, interaction, action) in &q_interaction {
         if *interaction == Interaction::Pressed {
@@ -219,13 +326,84 @@ pub fn settings_button_interaction_system(
                         window_mode: new_mode,
                     });
                 }
-                SettingsButtonAction::Back => {
-                    info!("Back button clicked, returning to main menu");
-                    mw_overlay.write(OverlayMessage {
-                        action: OverlayAction::Pop,
-                        overlay: OverlayState::Settings,
+                SettingsButtonAction::ToggleVsync => {
+                    info!("Toggling vsync to: {}", !display_settings.vsync);
+                    apply_frame_writer.write(ApplyFrameSettingsMessage {
+                        vsync: !display_settings.vsync,
+                        frame_cap: display_settings.frame_cap,
                     });
                 }
+                SettingsButtonAction::CycleFrameCap => {
+                    let new_cap = display_settings.frame_cap.next();
+                    info!("Setting frame cap to: {}", new_cap);
+                    apply_frame_writer.write(ApplyFrameSettingsMessage {
+                        vsync: display_settings.vsync,
+                        frame_cap: new_cap,
+                    });
+                }
+                SettingsButtonAction::ExportSettings => {
+                    let encoded = SharedSettings::from_display(&display_settings).to_base64();
+                    info!("Exported settings: {}", encoded);
+                    match clipboard::copy_text(&encoded) {
+                        Ok(()) => {
+                            mw_toast.write(ToastMessage::new("设置已复制到剪贴板"));
+                        }
+                        Err(error) => {
+                            // 无法使用剪贴板时显示字符串，供玩家手动抄写
+                            warn!("Could not copy settings: {}", error);
+                            mw_toast.write(ToastMessage::new(format!("设置字符串: {encoded}")));
+                        }
+                    }
+                }
+                SettingsButtonAction::ImportSettings => {
+                    // 从剪贴板粘贴分享字符串，校验交给 import_settings_system
+                    match clipboard::paste_text() {
+                        Ok(encoded) => {
+                            mw_import.write(ImportSettingsMessage { encoded });
+                        }
+                        Err(error) => {
+                            warn!("Could not paste settings: {}", error);
+                            mw_toast.write(ToastMessage::new("无法读取剪贴板"));
+                        }
+                    }
+                }
+                SettingsButtonAction::ToggleTheme => {
+                    let theme = theme_variant.next();
+                    mw_toast.write(ToastMessage::new(format!("主题: {}", theme.label())));
+                    mw_theme.write(ThemeMessage { theme });
+                }
+            }
+        }
+    }
+}
+
+/// System that validates an imported settings string and applies it
+///
+/// Invalid strings are rejected with a toast and leave the settings untouched.
+/// Accepted ones are saved by `save_settings_system` once applied.
+pub fn import_settings_system(
+    mut reader: MessageReader<ImportSettingsMessage>,
+    mut apply_settings_writer: MessageWriter<ApplyDisplaySettingsMessage>,
+    mut apply_frame_writer: MessageWriter<ApplyFrameSettingsMessage>,
+    mut mw_toast: MessageWriter<ToastMessage>,
+) {
+    for message in reader.read() {
+        match SharedSettings::from_base64(&message.encoded) {
+            Ok(shared) => {
+                info!("Importing settings: {:?}", shared);
+                apply_settings_writer.write(ApplyDisplaySettingsMessage {
+                    resolution: shared.resolution,
+                    window_mode: shared.window_mode,
+                });
+                apply_frame_writer.write(ApplyFrameSettingsMessage {
+                    vsync: shared.vsync,
+                    frame_cap: shared.frame_cap,
+                });
+                mw_toast.write(ToastMessage::new("设置已导入"));
+            }
+            Err(error) => {
+                warn!("Rejected settings import: {}", error);
+                mw_toast.write(ToastMessage::new("设置字符串无效"));
             }
         }
     }
@@ -1,6 +1,13 @@
-use crate::state::OverlayState;
+use crate::plugins::ui::navigation::NavigationGraph;
+use crate::state::{GameState, OverlayState};
 use bevy::prelude::*;
 
+/// ZIndex of the overlay background; each stacked overlay renders one level higher
+pub const OVERLAY_BASE_Z_INDEX: i32 = 1;
+
+/// Ordered stack of open overlays, the top is the one receiving input
+///
+/// Overlays beneath the top stay spawned; `OverlayState` follows the top.
 #[derive(Resource, Default)]
 pub struct MenuStack {
     stack: Vec<OverlayState>,
@@ -22,10 +29,22 @@ impl MenuStack {
         popped
     }
 
+    /// Swap the top overlay for another, pushing when the stack is empty
+    pub fn replace(&mut self, state: OverlayState) -> Option<OverlayState> {
+        let replaced = self.stack.pop();
+        self.push(state);
+        replaced
+    }
+
     pub fn peek(&self) -> Option<&OverlayState> {
         self.stack.last()
     }
 
+    /// ZIndex for the top overlay's root, above the background and lower levels
+    pub fn z_index(&self) -> ZIndex {
+        ZIndex(OVERLAY_BASE_Z_INDEX + self.stack.len() as i32)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.stack.is_empty()
     }
@@ -39,3 +58,138 @@ impl MenuStack {
         self.stack.clear();
     }
 }
+
+/// Navigation graphs of the overlays (or screen) covered by another overlay,
+/// the most recently covered last
+///
+/// Restored, with their focus, when the overlay above them closes.
+#[derive(Resource, Default)]
+pub struct CoveredNavigation {
+    stack: Vec<NavigationGraph>,
+}
+
+impl CoveredNavigation {
+    pub fn push(&mut self, graph: NavigationGraph) {
+        self.stack.push(graph);
+    }
+
+    pub fn pop(&mut self) -> Option<NavigationGraph> {
+        self.stack.pop()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+/// History of entered GameState screens, the top is the current screen
+///
+/// Overlays are tracked separately by MenuStack; Back unwinds overlays first.
+#[derive(Resource, Default)]
+pub struct MenuBackStack {
+    stack: Vec<GameState>,
+}
+
+impl MenuBackStack {
+    /// Record entering a screen
+    ///
+    /// Re-entering a screen already in the history cuts the history back to
+    /// it, so Main → Match3 → Main leaves just Main instead of growing.
+    pub fn push(&mut self, state: GameState) {
+        if let Some(index) = self.stack.iter().position(|&entry| entry == state) {
+            if index + 1 < self.stack.len() {
+                self.stack.truncate(index + 1);
+                debug!(
+                    "MenuBackStack: back to {:?}, depth: {}",
+                    state,
+                    self.stack.len()
+                );
+            }
+            return;
+        }
+        self.stack.push(state);
+        debug!(
+            "MenuBackStack: push {:?}, depth: {}",
+            state,
+            self.stack.len()
+        );
+    }
+
+    /// Leave the current screen and return the one to go back to
+    ///
+    /// Returns None at the root screen, leaving the stack untouched.
+    pub fn back(&mut self) -> Option<GameState> {
+        if self.stack.len() < 2 {
+            return None;
+        }
+        self.stack.pop();
+        let previous = self.stack.last().copied();
+        debug!(
+            "MenuBackStack: back to {:?}, depth: {}",
+            previous,
+            self.stack.len()
+        );
+        previous
+    }
+
+    pub fn current(&self) -> Option<&GameState> {
+        self.stack.last()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn clear(&mut self) {
+        debug!("MenuBackStack: clear entire stack");
+        self.stack.clear();
+    }
+}
+
+/// Target aspect ratio (width / height) for the rendered UI
+///
+/// When present, cameras render into a centered region of this aspect and
+/// the rest of the window is filled with letterbox/pillarbox bars.
+/// Remove the resource to go back to full-window layout.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct TargetAspect(pub f32);
+
+/// Font being checked until it finishes loading: the theme font, then the
+/// fallback if the theme font failed
+#[derive(Resource)]
+pub struct ThemeFontCheck {
+    pub path: String,
+    pub handle: Handle<Font>,
+}
+
+/// Whether UI animations play, set from `UiPlugin::with_animations`
+///
+/// When off, animated elements jump straight to their end state.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct UiAnimations(pub bool);
+
+/// Accessibility options for the UI
+#[derive(Resource, Clone, Debug, Default)]
+pub struct AccessibilitySettings {
+    /// Draw an outline around the focused element so focus doesn't rely on color
+    pub focus_ring: bool,
+    /// Skip decorative motion such as the navigation edge bump
+    pub reduced_motion: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revisiting_a_screen_truncates_back_history() {
+        let mut back_stack = MenuBackStack::default();
+        back_stack.push(GameState::MainMenu);
+        back_stack.push(GameState::Match3);
+        back_stack.push(GameState::MainMenu);
+
+        assert_eq!(back_stack.depth(), 1);
+        assert_eq!(back_stack.current(), Some(&GameState::MainMenu));
+        assert_eq!(back_stack.back(), None);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::state::GameState;
+use bevy::ecs::schedule::ScheduleConfigs;
+use bevy::ecs::system::ScheduleSystem;
+use bevy::prelude::*;
+
+/// 全屏界面 (主菜单、关卡选择等) 的统一描述
+///
+/// 通过 `app.add_screen::<S>()` 注册：进入状态时运行 `setup`，
+/// 状态激活时每帧运行 `update`，退出时清理所有带 `Marker` 的实体。
+pub trait Screen {
+    /// 屏幕所有根实体上的标记组件
+    type Marker: Component;
+
+    /// 屏幕对应的游戏状态
+    const STATE: GameState;
+
+    /// 进入屏幕时运行的系统
+    fn setup() -> ScheduleConfigs<ScheduleSystem>;
+
+    /// 屏幕激活期间每帧运行的系统
+    fn update() -> ScheduleConfigs<ScheduleSystem>;
+}
+
+/// 销毁带有标记组件的所有实体
+pub fn cleanup_screen<M: Component>(mut commands: Commands, query: Query<Entity, With<M>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub trait AppScreenExt {
+    fn add_screen<S: Screen>(&mut self) -> &mut Self;
+}
+
+impl AppScreenExt for App {
+    fn add_screen<S: Screen>(&mut self) -> &mut Self {
+        self.add_systems(OnEnter(S::STATE), S::setup())
+            .add_systems(Update, S::update().run_if(in_state(S::STATE)))
+            .add_systems(OnExit(S::STATE), cleanup_screen::<S::Marker>)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::plugins::ui::button_builder::{ButtonSize, spawn_button_sized};
+use crate::plugins::ui::components::HoldRepeat;
+use crate::plugins::ui::fonts::FontProvider;
+use crate::plugins::ui::styles::{Theme, ThemeSpacing};
+use crate::plugins::ui::ui_builders::{ContainerBuilder, TextLevel, text_styled};
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::prelude::*;
+
+/// Numeric stepper state, stored on the stepper row
+#[derive(Component, Clone, Debug)]
+pub struct Stepper {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    /// Decimal places shown in the value label (0 for integers)
+    pub precision: usize,
+}
+
+impl Stepper {
+    /// Moves the value by a number of steps, clamped to the bounds
+    ///
+    /// Returns true if the value changed.
+    pub fn step_by(&mut self, steps: f32) -> bool {
+        let new_value = (self.value + steps * self.step).clamp(self.min, self.max);
+        let changed = new_value != self.value;
+        self.value = new_value;
+        changed
+    }
+
+    pub fn at_min(&self) -> bool {
+        self.value <= self.min
+    }
+
+    pub fn at_max(&self) -> bool {
+        self.value >= self.max
+    }
+
+    pub fn label(&self) -> String {
+        format!("{:.*}", self.precision, self.value)
+    }
+}
+
+/// Action of the − / + buttons inside a stepper row
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepperButtonAction {
+    Decrease,
+    Increase,
+}
+
+/// Marker for the value label inside a stepper row
+#[derive(Component)]
+pub struct StepperValueLabel;
+
+/// Message emitted when a stepper value changes
+#[derive(Message, Clone, Debug)]
+pub struct StepperChanged {
+    pub stepper: Entity,
+    pub value: f32,
+}
+
+/// Hold delay and repeat interval of the − / + buttons, in seconds
+const STEPPER_HOLD_DELAY: f32 = 0.4;
+const STEPPER_HOLD_INTERVAL: f32 = 0.1;
+
+/// Builder for a "− value +" stepper row
+pub struct StepperBuilder {
+    stepper: Stepper,
+}
+
+impl StepperBuilder {
+    pub fn new(value: f32, min: f32, max: f32, step: f32) -> Self {
+        Self {
+            stepper: Stepper {
+                value: value.clamp(min, max),
+                min,
+                max,
+                step,
+                precision: 0,
+            },
+        }
+    }
+
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.stepper.precision = decimals;
+        self
+    }
+
+    /// Spawn the stepper row and return its entity
+    ///
+    /// The row is a button so it can be added to a ButtonNavigationBuilder;
+    /// while it is focused, Left/Right adjust the value.
+    pub fn spawn<B: Bundle>(
+        self,
+        parent: &mut RelatedSpawnerCommands<ChildOf>,
+        bundle: B,
+        theme: &Theme,
+        asset_server: &impl FontProvider,
+    ) -> Entity {
+        let label = self.stepper.label();
+        let button_size = ButtonSize::Custom(theme.spacing.xxl, theme.spacing.xl);
+
+        ContainerBuilder::new()
+            .horizontal()
+            .centered()
+            .gap_themed(ThemeSpacing::SM, theme)
+            .padding_themed(ThemeSpacing::XS, theme)
+            .background(theme.button.normal)
+            .spawn_in(parent, (Button, self.stepper, bundle), |row| {
+                let decrease = spawn_button_sized(
+                    row,
+                    "−",
+                    StepperButtonAction::Decrease,
+                    theme,
+                    asset_server,
+                    button_size,
+                );
+                row.spawn((
+                    text_styled(label, TextLevel::Body, theme, asset_server),
+                    StepperValueLabel,
+                ));
+                let increase = spawn_button_sized(
+                    row,
+                    "+",
+                    StepperButtonAction::Increase,
+                    theme,
+                    asset_server,
+                    button_size,
+                );
+
+                for entity in [decrease, increase] {
+                    row.commands()
+                        .entity(entity)
+                        .insert(HoldRepeat::new(STEPPER_HOLD_DELAY, STEPPER_HOLD_INTERVAL));
+                }
+            })
+    }
+}
@@ -1,56 +1,105 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 use crate::plugins::ui::button_builder::ButtonStyle;
 
+/// 缩放后的最小字号，避免缩放系数过小时文本消失
+pub const MIN_FONT_SIZE: f32 = 8.0;
+
+/// 内置主题使用的字体
+pub const DEFAULT_FONT_PATH: &str = "fonts/AlibabaPuHuiTi-3-65-Medium.ttf";
+
+/// 随游戏一起打包的回退字体，主题字体加载失败时使用
+///
+/// 必须是与主题字体不同的文件，否则回退时会再次加载刚失败的字体。
+/// DejaVu Sans 不含中文字形，仅保证拉丁文本可读
+pub const FALLBACK_FONT_PATH: &str = "fonts/DejaVuSans.ttf";
+
 /// 主题资源 - 全局UI样式配置
-#[derive(Resource, Clone)]
+///
+/// 可由 `assets/themes/*.theme.ron` 定义，颜色写作 `"#RRGGBB"`/`"#RRGGBBAA"`
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct Theme {
     pub colors: ColorPalette,
     pub typography: Typography,
     pub spacing: Spacing,
     pub button: ButtonStyle,
+    /// 危险操作按钮样式 (退出、删除等)
+    pub button_danger: ButtonStyle,
+    /// 按钮尺寸缩放系数 (高DPI显示器)，默认 1.0
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
 }
 
 /// 颜色调色板
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ColorPalette {
     // 主要颜色
+    #[serde(with = "color_hex")]
     pub primary: Color,
+    #[serde(with = "color_hex")]
     pub secondary: Color,
+    #[serde(with = "color_hex")]
     pub background: Color,
+    #[serde(with = "color_hex")]
     pub surface: Color,
+    #[serde(with = "color_hex")]
+    pub border: Color,
 
     // 文本颜色
+    #[serde(with = "color_hex")]
     pub text_primary: Color,
+    #[serde(with = "color_hex")]
     pub text_secondary: Color,
+    #[serde(with = "color_hex")]
     pub text_disabled: Color,
 
+    // 语义颜色
+    #[serde(with = "color_hex")]
+    pub error: Color,
+
     // 按钮颜色 (与ButtonStyle同步)
+    #[serde(with = "color_hex")]
     pub button_normal: Color,
+    #[serde(with = "color_hex")]
     pub button_hovered: Color,
+    #[serde(with = "color_hex")]
     pub button_pressed: Color,
+    #[serde(with = "color_hex")]
     pub button_focused: Color,
+    #[serde(with = "color_hex")]
     pub button_selected: Color,
 }
 
 /// 排版系统
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Typography {
     pub font_path: String,
 
     // 字体大小
+    /// 超大号 (计分板等)，大于 H1
+    #[serde(default = "default_size_display")]
+    pub size_display: f32,
     pub size_h1: f32,
     pub size_h2: f32,
     pub size_h3: f32,
     pub size_body: f32,
     pub size_small: f32,
+    /// 说明文字，小于 Small
+    #[serde(default = "default_size_caption")]
+    pub size_caption: f32,
 
     // 行高
     pub line_height: f32,
+
+    /// 全局字号缩放系数 (无障碍)，默认 1.0
+    #[serde(default = "default_font_scale")]
+    pub scale: f32,
 }
 
 /// 间距系统 - 使用8px基准的间距尺度
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Spacing {
     pub xs: f32,  // 4px
     pub sm: f32,  // 8px
@@ -58,6 +107,114 @@ pub struct Spacing {
     pub lg: f32,  // 24px
     pub xl: f32,  // 32px
     pub xxl: f32, // 48px
+    /// 整体间距缩放系数 (紧凑/舒适/宽松)，默认 1.0
+    #[serde(default = "default_spacing_scale")]
+    pub scale: f32,
+}
+
+fn default_spacing_scale() -> f32 {
+    1.0
+}
+
+fn default_ui_scale() -> f32 {
+    1.0
+}
+
+fn default_font_scale() -> f32 {
+    1.0
+}
+
+fn default_size_display() -> f32 {
+    72.0
+}
+
+fn default_size_caption() -> f32 {
+    12.0
+}
+
+impl Typography {
+    /// 应用缩放系数后的字号，不低于 `MIN_FONT_SIZE`
+    pub fn scaled(&self, size: f32) -> f32 {
+        (size * self.scale).max(MIN_FONT_SIZE)
+    }
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self {
+            font_path: DEFAULT_FONT_PATH.to_string(),
+            size_display: default_size_display(),
+            size_h1: 48.0,
+            size_h2: 36.0,
+            size_h3: 28.0,
+            size_body: 20.0,
+            size_small: 16.0,
+            size_caption: default_size_caption(),
+            line_height: 1.5,
+            scale: 1.0,
+        }
+    }
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            xs: 4.0,
+            sm: 8.0,
+            md: 16.0,
+            lg: 24.0,
+            xl: 32.0,
+            xxl: 48.0,
+            scale: 1.0,
+        }
+    }
+}
+
+/// Color 与十六进制字符串互转，用于主题文件的序列化
+pub(crate) mod color_hex {
+    use bevy::prelude::*;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.to_srgba().to_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Srgba::hex(&hex)
+            .map(Color::from)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// 主题文件读取失败的原因
+#[derive(Debug)]
+pub enum ThemeLoadError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for ThemeLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeLoadError::Io(error) => write!(f, "could not read theme file: {error}"),
+            ThemeLoadError::Parse(error) => write!(f, "invalid theme file: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeLoadError {}
+
+impl From<std::io::Error> for ThemeLoadError {
+    fn from(error: std::io::Error) -> Self {
+        ThemeLoadError::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for ThemeLoadError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        ThemeLoadError::Parse(error)
+    }
 }
 
 impl Default for Theme {
@@ -67,6 +224,26 @@ impl Default for Theme {
 }
 
 impl Theme {
+    /// 按内置主题构造
+    pub fn variant(variant: ThemeVariant) -> Self {
+        match variant {
+            ThemeVariant::Dark => Self::dark(),
+            ThemeVariant::Light => Self::light(),
+            ThemeVariant::HighContrast => Self::high_contrast(),
+        }
+    }
+
+    /// 从RON文件读取主题
+    pub fn from_ron(path: impl AsRef<Path>) -> Result<Self, ThemeLoadError> {
+        let bytes = std::fs::read(path)?;
+        Self::from_ron_bytes(&bytes)
+    }
+
+    /// 从RON内容解析主题
+    pub fn from_ron_bytes(bytes: &[u8]) -> Result<Self, ThemeLoadError> {
+        Ok(ron::de::from_bytes(bytes)?)
+    }
+
     /// 深色主题 (默认)
     pub fn dark() -> Self {
         let colors = ColorPalette {
@@ -74,11 +251,14 @@ impl Theme {
             secondary: Color::srgb(0.5, 0.3, 0.8),
             background: Color::srgb(0.1, 0.1, 0.1),
             surface: Color::srgb(0.15, 0.15, 0.15),
+            border: Color::srgb(0.3, 0.3, 0.3),
 
             text_primary: Color::WHITE,
             text_secondary: Color::srgb(0.7, 0.7, 0.7),
             text_disabled: Color::srgb(0.4, 0.4, 0.4),
 
+            error: Color::srgb(0.8, 0.25, 0.25),
+
             button_normal: Color::srgb(0.15, 0.15, 0.15),
             button_hovered: Color::srgb(0.25, 0.25, 0.25),
             button_pressed: Color::srgb(0.35, 0.75, 0.35),
@@ -93,25 +273,20 @@ impl Theme {
                 pressed: colors.button_pressed,
                 focused: colors.button_focused,
                 selected: colors.button_selected,
+                border_radius: 8.0,
             },
-            colors,
-            typography: Typography {
-                font_path: "fonts/AlibabaPuHuiTi-3-65-Medium.ttf".to_string(),
-                size_h1: 48.0,
-                size_h2: 36.0,
-                size_h3: 28.0,
-                size_body: 20.0,
-                size_small: 16.0,
-                line_height: 1.5,
-            },
-            spacing: Spacing {
-                xs: 4.0,
-                sm: 8.0,
-                md: 16.0,
-                lg: 24.0,
-                xl: 32.0,
-                xxl: 48.0,
+            button_danger: ButtonStyle {
+                normal: Color::srgb(0.45, 0.12, 0.12),
+                hovered: Color::srgb(0.6, 0.18, 0.18),
+                pressed: colors.error,
+                focused: Color::srgb(0.55, 0.2, 0.3),
+                selected: Color::srgb(0.45, 0.12, 0.12),
+                border_radius: 8.0,
             },
+            colors,
+            typography: Typography::default(),
+            spacing: Spacing::default(),
+            ui_scale: 1.0,
         }
     }
 
@@ -122,11 +297,14 @@ impl Theme {
             secondary: Color::srgb(0.4, 0.2, 0.7),
             background: Color::srgb(0.95, 0.95, 0.95),
             surface: Color::WHITE,
+            border: Color::srgb(0.8, 0.8, 0.8),
 
             text_primary: Color::srgb(0.1, 0.1, 0.1),
             text_secondary: Color::srgb(0.3, 0.3, 0.3),
             text_disabled: Color::srgb(0.6, 0.6, 0.6),
 
+            error: Color::srgb(0.85, 0.3, 0.3),
+
             button_normal: Color::srgb(0.85, 0.85, 0.85),
             button_hovered: Color::srgb(0.75, 0.75, 0.75),
             button_pressed: Color::srgb(0.35, 0.75, 0.35),
@@ -141,29 +319,116 @@ impl Theme {
                 pressed: colors.button_pressed,
                 focused: colors.button_focused,
                 selected: colors.button_selected,
+                border_radius: 4.0,
+            },
+            button_danger: ButtonStyle {
+                normal: Color::srgb(0.95, 0.75, 0.75),
+                hovered: Color::srgb(0.92, 0.6, 0.6),
+                pressed: colors.error,
+                focused: Color::srgb(0.9, 0.65, 0.8),
+                selected: Color::srgb(0.95, 0.75, 0.75),
+                border_radius: 4.0,
             },
             colors,
-            typography: Typography {
-                font_path: "fonts/AlibabaPuHuiTi-3-65-Medium.ttf".to_string(),
-                size_h1: 48.0,
-                size_h2: 36.0,
-                size_h3: 28.0,
-                size_body: 20.0,
-                size_small: 16.0,
-                line_height: 1.5,
+            typography: Typography::default(),
+            spacing: Spacing::default(),
+            ui_scale: 1.0,
+        }
+    }
+
+    /// 高对比度主题 (无障碍)
+    ///
+    /// 纯黑背景、纯白文本；按钮各状态 (含危险与关闭按钮) 与白色文本的对比度均不低于 7:1
+    pub fn high_contrast() -> Self {
+        let colors = ColorPalette {
+            primary: Color::srgb_u8(0xFF, 0xFF, 0x00),
+            secondary: Color::srgb_u8(0x00, 0xFF, 0xFF),
+            background: Color::BLACK,
+            surface: Color::BLACK,
+            border: Color::WHITE,
+
+            text_primary: Color::WHITE,
+            text_secondary: Color::srgb_u8(0xE0, 0xE0, 0xE0),
+            text_disabled: Color::srgb_u8(0xA0, 0xA0, 0xA0),
+
+            // 关闭按钮悬停/按下时作背景，需深色以配白色文本
+            error: Color::srgb_u8(0xB0, 0x00, 0x00),
+
+            button_normal: Color::BLACK,
+            button_hovered: Color::srgb_u8(0x00, 0x00, 0xC8),
+            button_pressed: Color::srgb_u8(0x00, 0x64, 0x00),
+            button_focused: Color::srgb_u8(0x6A, 0x00, 0xB0),
+            button_selected: Color::srgb_u8(0x00, 0x45, 0x8C),
+        };
+
+        Self {
+            button: ButtonStyle {
+                normal: colors.button_normal,
+                hovered: colors.button_hovered,
+                pressed: colors.button_pressed,
+                focused: colors.button_focused,
+                selected: colors.button_selected,
+                border_radius: 0.0,
             },
-            spacing: Spacing {
-                xs: 4.0,
-                sm: 8.0,
-                md: 16.0,
-                lg: 24.0,
-                xl: 32.0,
-                xxl: 48.0,
+            button_danger: ButtonStyle {
+                normal: Color::srgb_u8(0x7A, 0x00, 0x00),
+                hovered: Color::srgb_u8(0xA0, 0x00, 0x00),
+                pressed: Color::srgb_u8(0x50, 0x00, 0x00),
+                focused: Color::srgb_u8(0x80, 0x00, 0x40),
+                selected: Color::srgb_u8(0x7A, 0x00, 0x00),
+                border_radius: 0.0,
             },
+            colors,
+            typography: Typography::default(),
+            spacing: Spacing::default(),
+            ui_scale: 1.0,
         }
     }
 }
 
+/// 内置主题
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeVariant {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeVariant {
+    /// 对应的主题文件路径
+    pub fn asset_path(&self) -> &'static str {
+        match self {
+            ThemeVariant::Dark => "themes/dark.theme.ron",
+            ThemeVariant::Light => "themes/light.theme.ron",
+            ThemeVariant::HighContrast => "themes/high_contrast.theme.ron",
+        }
+    }
+
+    /// 设置中切换到的下一个主题
+    pub fn next(&self) -> Self {
+        match self {
+            ThemeVariant::Dark => ThemeVariant::Light,
+            ThemeVariant::Light => ThemeVariant::HighContrast,
+            ThemeVariant::HighContrast => ThemeVariant::Dark,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeVariant::Dark => "深色",
+            ThemeVariant::Light => "浅色",
+            ThemeVariant::HighContrast => "高对比度",
+        }
+    }
+}
+
+/// 请求运行时切换主题
+#[derive(Message, Clone, Copy, Debug)]
+pub struct ThemeMessage {
+    pub theme: ThemeVariant,
+}
+
 /// 主题间距枚举 - 用于从Theme获取间距值
 #[derive(Clone, Copy, Debug)]
 pub enum ThemeSpacing {
@@ -176,15 +441,17 @@ pub enum ThemeSpacing {
 }
 
 impl ThemeSpacing {
+    /// 获取间距值，已乘以 `spacing.scale`
     pub fn get_value(&self, spacing: &Spacing) -> f32 {
-        match self {
+        let base = match self {
             ThemeSpacing::XS => spacing.xs,
             ThemeSpacing::SM => spacing.sm,
             ThemeSpacing::MD => spacing.md,
             ThemeSpacing::LG => spacing.lg,
             ThemeSpacing::XL => spacing.xl,
             ThemeSpacing::XXL => spacing.xxl,
-        }
+        };
+        base * spacing.scale
     }
 }
 
@@ -195,8 +462,11 @@ pub enum ThemedColor {
     Secondary,
     Background,
     Surface,
+    Border,
     TextPrimary,
     TextSecondary,
+    TextDisabled,
+    Error,
 }
 
 impl ThemedColor {
@@ -206,8 +476,97 @@ impl ThemedColor {
             ThemedColor::Secondary => palette.secondary,
             ThemedColor::Background => palette.background,
             ThemedColor::Surface => palette.surface,
+            ThemedColor::Border => palette.border,
             ThemedColor::TextPrimary => palette.text_primary,
             ThemedColor::TextSecondary => palette.text_secondary,
+            ThemedColor::TextDisabled => palette.text_disabled,
+            ThemedColor::Error => palette.error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spacing_scale_multiplies_the_base_value() {
+        let spacing = Spacing {
+            scale: 1.5,
+            ..default()
+        };
+        assert_eq!(ThemeSpacing::MD.get_value(&spacing), 24.0);
+        assert_eq!(ThemeSpacing::MD.get_value(&Spacing::default()), 16.0);
+    }
+
+    /// WCAG 对比度
+    fn contrast_ratio(a: Color, b: Color) -> f32 {
+        let luminance = |color: Color| {
+            let linear = color.to_linear();
+            0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue
+        };
+        let (a, b) = (luminance(a), luminance(b));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    #[test]
+    fn high_contrast_text_meets_seven_to_one() {
+        use crate::plugins::ui::button_builder::ButtonRole;
+
+        let theme = Theme::high_contrast();
+        let colors = &theme.colors;
+
+        // 正文和说明文字位于背景/表面色上
+        let mut pairs = Vec::new();
+        for text in [colors.text_primary, colors.text_secondary, colors.text_disabled] {
+            pairs.push((text, colors.background));
+            pairs.push((text, colors.surface));
+        }
+
+        // 按钮文字位于各状态的按钮背景上，包括各个按钮角色
+        let styles = [
+            theme.button.clone(),
+            ButtonRole::Danger.resolve(&theme),
+            ButtonRole::Close.resolve(&theme),
+        ];
+        for style in &styles {
+            for background in [
+                style.normal,
+                style.hovered,
+                style.pressed,
+                style.focused,
+                style.selected,
+            ] {
+                pairs.push((colors.text_primary, background));
+            }
+        }
+
+        for (text, background) in pairs {
+            let ratio = contrast_ratio(text, background);
+            assert!(
+                ratio >= 7.0,
+                "{:?} on {:?} has contrast {ratio:.2}",
+                text.to_srgba().to_hex(),
+                background.to_srgba().to_hex()
+            );
+        }
+    }
+
+    #[test]
+    fn fallback_font_differs_from_every_theme_font() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+        assert!(assets.join(FALLBACK_FONT_PATH).is_file());
+
+        for variant in [
+            ThemeVariant::Dark,
+            ThemeVariant::Light,
+            ThemeVariant::HighContrast,
+        ] {
+            assert_ne!(Theme::variant(variant).typography.font_path, FALLBACK_FONT_PATH);
+
+            let theme = Theme::from_ron(assets.join(variant.asset_path()))
+                .expect("bundled theme file should parse");
+            assert_ne!(theme.typography.font_path, FALLBACK_FONT_PATH);
         }
     }
 }
@@ -0,0 +1,85 @@
+use crate::plugins::ui::components::{Disabled, DisabledColorCache, Focused};
+use crate::plugins::ui::navigation::NavigationGraph;
+use crate::plugins::ui::styles::Theme;
+use bevy::prelude::*;
+
+/// Alpha of the muted surface used as background of disabled widgets
+const DISABLED_BACKGROUND_ALPHA: f32 = 0.5;
+
+/// System that dims newly disabled widgets and blocks their interaction
+///
+/// Background becomes a translucent surface, every text in the widget uses
+/// `text_disabled`, and `Pickable::IGNORE` stops pointer hover and presses.
+pub fn apply_disabled_style_system(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut nav_graph: ResMut<NavigationGraph>,
+    mut q_disabled: Query<
+        (
+            Entity,
+            Option<&mut BackgroundColor>,
+            Option<&mut Interaction>,
+        ),
+        Added<Disabled>,
+    >,
+    q_children: Query<&Children>,
+    mut q_text: Query<&mut TextColor>,
+) {
+    for (entity, background, interaction) in q_disabled.iter_mut() {
+        let background = background.map(|mut background| {
+            let previous = background.0;
+            background.0 = theme.colors.surface.with_alpha(DISABLED_BACKGROUND_ALPHA);
+            previous
+        });
+
+        let mut text = Vec::new();
+        for text_entity in std::iter::once(entity).chain(q_children.iter_descendants(entity)) {
+            if let Ok(mut color) = q_text.get_mut(text_entity) {
+                text.push((text_entity, color.0));
+                color.0 = theme.colors.text_disabled;
+            }
+        }
+
+        if let Some(mut interaction) = interaction {
+            *interaction = Interaction::None;
+        }
+        if nav_graph.get_focused() == Some(entity) {
+            nav_graph.clear_focus();
+        }
+
+        commands
+            .entity(entity)
+            .remove::<Focused>()
+            .insert((DisabledColorCache { background, text }, Pickable::IGNORE));
+    }
+}
+
+/// System that restores cached colours and interaction when Disabled is removed
+pub fn restore_disabled_style_system(
+    mut commands: Commands,
+    mut removed: RemovedComponents<Disabled>,
+    q_cache: Query<&DisabledColorCache>,
+    mut q_background: Query<&mut BackgroundColor>,
+    mut q_text: Query<&mut TextColor>,
+) {
+    for entity in removed.read() {
+        let Ok(cache) = q_cache.get(entity) else {
+            continue;
+        };
+
+        if let Some(background) = cache.background
+            && let Ok(mut current) = q_background.get_mut(entity)
+        {
+            current.0 = background;
+        }
+        for &(text_entity, color) in &cache.text {
+            if let Ok(mut current) = q_text.get_mut(text_entity) {
+                current.0 = color;
+            }
+        }
+
+        commands
+            .entity(entity)
+            .remove::<(DisabledColorCache, Pickable)>();
+    }
+}
@@ -0,0 +1,87 @@
+use crate::plugins::ui::components::{FocusCursor, Focusable, Focused, ThemedBorder};
+use crate::plugins::ui::resources::{AccessibilitySettings, UiAnimations};
+use crate::plugins::ui::styles::{Theme, ThemedColor};
+use bevy::prelude::*;
+
+/// How quickly the cursor closes the distance to its target, per second
+const FOCUS_CURSOR_SPEED: f32 = 18.0;
+/// Gap between the cursor frame and the focused element
+const FOCUS_CURSOR_PADDING: f32 = 4.0;
+const FOCUS_CURSOR_WIDTH: f32 = 2.0;
+
+/// System that moves a single shared cursor frame onto the focused element
+///
+/// The cursor is hidden when nothing is focused or while the mouse hovers a
+/// focusable element. With reduced motion it snaps instead of gliding.
+pub fn focus_cursor_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Res<Theme>,
+    settings: Res<AccessibilitySettings>,
+    animations: Res<UiAnimations>,
+    q_focused: Query<(&ComputedNode, &UiGlobalTransform), With<Focused>>,
+    q_hovered: Query<&Interaction, With<Focusable>>,
+    mut q_cursor: Query<(&mut Node, &mut Visibility), With<FocusCursor>>,
+) {
+    let Ok((mut node, mut visibility)) = q_cursor.single_mut() else {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                border: UiRect::all(px(FOCUS_CURSOR_WIDTH)),
+                ..default()
+            },
+            BorderColor::all(theme.colors.primary),
+            ThemedBorder(ThemedColor::Primary),
+            Visibility::Hidden,
+            ZIndex(15),
+            Pickable::IGNORE,
+            FocusCursor,
+        ));
+        return;
+    };
+
+    let mouse_hover = q_hovered
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+    let target = q_focused
+        .single()
+        .ok()
+        .filter(|_| !mouse_hover)
+        .map(|(computed, transform)| {
+            let scale = computed.inverse_scale_factor();
+            let size = computed.size() * scale + Vec2::splat(FOCUS_CURSOR_PADDING * 2.0);
+            let center = transform.translation * scale;
+            Rect::from_center_size(center, size)
+        });
+
+    let Some(target) = target else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    let current = match (*visibility, node.left, node.top, node.width, node.height) {
+        (Visibility::Hidden, ..) => None,
+        (_, Val::Px(left), Val::Px(top), Val::Px(width), Val::Px(height)) => Some(
+            Rect::from_corners(Vec2::new(left, top), Vec2::new(left + width, top + height)),
+        ),
+        _ => None,
+    };
+
+    // 刚出现、减少动画或关闭动画时直接跳到目标
+    let rect = match current {
+        Some(current) if animations.0 && !settings.reduced_motion => {
+            let t = 1.0 - (-FOCUS_CURSOR_SPEED * time.delta_secs()).exp();
+            Rect::from_corners(
+                current.min.lerp(target.min, t),
+                current.max.lerp(target.max, t),
+            )
+        }
+        _ => target,
+    };
+
+    node.left = px(rect.min.x);
+    node.top = px(rect.min.y);
+    node.width = px(rect.width());
+    node.height = px(rect.height());
+    *visibility = Visibility::Inherited;
+}
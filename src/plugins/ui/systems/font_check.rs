@@ -0,0 +1,86 @@
+use crate::plugins::ui::fonts::{FontCache, FontFallback};
+use crate::plugins::ui::resources::ThemeFontCheck;
+use crate::plugins::ui::styles::{FALLBACK_FONT_PATH, Theme};
+#[cfg(debug_assertions)]
+use crate::plugins::ui::toast::ToastMessage;
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+/// Starts tracking the theme font so a failed load can be detected
+pub fn setup_font_check(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    asset_server: Res<AssetServer>,
+) {
+    let path = theme.typography.font_path.clone();
+    let handle = asset_server.load(&path);
+    commands.insert_resource(ThemeFontCheck { path, handle });
+}
+
+/// System that swaps in the fallback font when the theme font fails to load
+///
+/// The Theme keeps its font path; `Fonts` resolves the failed path to the
+/// fallback through FontFallback.
+pub fn check_theme_font_system(
+    mut commands: Commands,
+    check: Option<Res<ThemeFontCheck>>,
+    mut font_fallback: ResMut<FontFallback>,
+    asset_server: Res<AssetServer>,
+    mut q_text_fonts: Query<&mut TextFont>,
+    #[cfg(debug_assertions)] mut mw_toast: MessageWriter<ToastMessage>,
+) {
+    let Some(check) = check else {
+        return;
+    };
+
+    match asset_server.load_state(&check.handle) {
+        LoadState::Loaded => {
+            debug!("Font loaded: {}", check.path);
+            commands.remove_resource::<ThemeFontCheck>();
+        }
+        LoadState::Failed(err) => {
+            error!("Failed to load font '{}': {}", check.path, err);
+
+            if check.path == FALLBACK_FONT_PATH {
+                error!("Fallback font is unavailable, text will use the default font");
+                commands.remove_resource::<ThemeFontCheck>();
+                return;
+            }
+
+            warn!("Falling back to bundled font '{}'", FALLBACK_FONT_PATH);
+            let fallback = asset_server.load(FALLBACK_FONT_PATH);
+            font_fallback.replace(&check.path, fallback.clone());
+
+            // 已生成的文本同样替换为回退字体
+            for mut text_font in q_text_fonts.iter_mut() {
+                if text_font.font == check.handle {
+                    text_font.font = fallback.clone();
+                }
+            }
+
+            #[cfg(debug_assertions)]
+            mw_toast.write(ToastMessage::new(format!(
+                "字体加载失败，已使用 {}",
+                FALLBACK_FONT_PATH
+            )));
+
+            commands.insert_resource(ThemeFontCheck {
+                path: FALLBACK_FONT_PATH.to_string(),
+                handle: fallback,
+            });
+        }
+        _ => {}
+    }
+}
+
+/// System that refreshes the FontCache when the theme font path changes
+pub fn update_font_cache_system(
+    theme: Res<Theme>,
+    mut font_cache: ResMut<FontCache>,
+    asset_server: Res<AssetServer>,
+) {
+    if theme.is_changed() && font_cache.path() != theme.typography.font_path {
+        debug!("FontCache: reload {}", theme.typography.font_path);
+        *font_cache = FontCache::new(&theme.typography.font_path, &asset_server);
+    }
+}
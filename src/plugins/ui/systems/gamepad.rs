@@ -0,0 +1,37 @@
+use crate::plugins::ui::components::{Disabled, Focusable, Focused};
+use crate::plugins::ui::navigation::NavigationGraph;
+use crate::plugins::ui::toast::ToastMessage;
+use bevy::input::gamepad::GamepadConnectionEvent;
+use bevy::prelude::*;
+
+/// System that reports gamepad connection changes and keeps a focus target available
+///
+/// On connect, focuses the first focusable button if nothing is focused yet.
+/// On disconnect, the current focus is kept as is; mouse hover keeps working
+/// through `Interaction` regardless of focus.
+pub fn gamepad_connection_system(
+    mut commands: Commands,
+    mut reader: MessageReader<GamepadConnectionEvent>,
+    mut mw_toast: MessageWriter<ToastMessage>,
+    mut nav_graph: ResMut<NavigationGraph>,
+    q_focused: Query<Entity, With<Focused>>,
+    q_focusable: Query<Entity, (With<Focusable>, Without<Disabled>)>,
+) {
+    for event in reader.read() {
+        if event.connected() {
+            info!("Gamepad {:?} connected", event.gamepad);
+            mw_toast.write(ToastMessage::new("手柄已连接"));
+
+            if q_focused.is_empty()
+                && let Some(first_button) = q_focusable.iter().next()
+            {
+                commands.entity(first_button).insert(Focused);
+                nav_graph.set_focus(first_button);
+                info!("Focused first button {:?}", first_button);
+            }
+        } else {
+            info!("Gamepad {:?} disconnected", event.gamepad);
+            mw_toast.write(ToastMessage::new("手柄已断开"));
+        }
+    }
+}
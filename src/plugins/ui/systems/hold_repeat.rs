@@ -0,0 +1,27 @@
+use crate::plugins::ui::components::HoldRepeat;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+/// System that re-fires held buttons by re-triggering change detection on Interaction
+///
+/// Action handlers filtering on `Changed<Interaction>` see the press again,
+/// so no handler needs to know about repetition.
+pub fn hold_repeat_system(
+    time: Res<Time>,
+    mut q_buttons: Query<
+        (&mut Interaction, &mut HoldRepeat, &RelativeCursorPosition),
+        With<Button>,
+    >,
+) {
+    for (mut interaction, mut hold_repeat, cursor) in q_buttons.iter_mut() {
+        // 松开或移出按钮后停止重复
+        if *interaction != Interaction::Pressed || !cursor.cursor_over() {
+            hold_repeat.reset();
+            continue;
+        }
+
+        if hold_repeat.tick(time.delta_secs()) {
+            interaction.set_changed();
+        }
+    }
+}
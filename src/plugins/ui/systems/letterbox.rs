@@ -0,0 +1,114 @@
+use crate::plugins::ui::resources::TargetAspect;
+use crate::plugins::ui::styles::Theme;
+use bevy::camera::{ClearColorConfig, Viewport};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Marker for the camera that draws the letterbox/pillarbox bars
+#[derive(Component)]
+pub struct LetterboxCamera;
+
+/// Computes a centered viewport of the given aspect ratio inside the window
+fn letterbox_viewport(window_size: UVec2, aspect: f32) -> Option<Viewport> {
+    if window_size.x == 0 || window_size.y == 0 || aspect <= 0.0 {
+        return None;
+    }
+
+    let window_aspect = window_size.x as f32 / window_size.y as f32;
+    let size = if window_aspect > aspect {
+        // 窗口更宽，左右留边
+        UVec2::new((window_size.y as f32 * aspect).round() as u32, window_size.y)
+    } else {
+        // 窗口更高，上下留边
+        UVec2::new(window_size.x, (window_size.x as f32 / aspect).round() as u32)
+    };
+
+    Some(Viewport {
+        physical_position: (window_size - size) / 2,
+        physical_size: size,
+        ..default()
+    })
+}
+
+/// System that keeps camera viewports at the TargetAspect ratio
+///
+/// The bars are drawn by a separate low-order camera cleared with
+/// `theme.colors.background`. Removing TargetAspect restores full-window cameras.
+pub fn letterbox_system(
+    mut commands: Commands,
+    target_aspect: Option<Res<TargetAspect>>,
+    theme: Res<Theme>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    mut q_cameras: Query<&mut Camera, (With<Camera2d>, Without<LetterboxCamera>)>,
+    mut q_letterbox: Query<(Entity, &mut Camera), With<LetterboxCamera>>,
+) {
+    let Ok(window) = q_window.single() else {
+        return;
+    };
+
+    let viewport =
+        target_aspect.and_then(|aspect| letterbox_viewport(window.physical_size(), aspect.0));
+
+    for mut camera in q_cameras.iter_mut() {
+        let unchanged = match (&camera.viewport, &viewport) {
+            (None, None) => true,
+            (Some(current), Some(target)) => {
+                current.physical_position == target.physical_position
+                    && current.physical_size == target.physical_size
+            }
+            _ => false,
+        };
+        if !unchanged {
+            camera.viewport = viewport.clone();
+        }
+    }
+
+    match (viewport.is_some(), q_letterbox.single_mut()) {
+        (true, Ok((_, mut camera))) => {
+            if theme.is_changed() {
+                camera.clear_color = ClearColorConfig::Custom(theme.colors.background);
+            }
+        }
+        (true, Err(_)) => {
+            commands.spawn((
+                Camera2d,
+                Camera {
+                    order: -1,
+                    clear_color: ClearColorConfig::Custom(theme.colors.background),
+                    ..default()
+                },
+                LetterboxCamera,
+            ));
+        }
+        (false, Ok((entity, _))) => {
+            commands.entity(entity).despawn();
+        }
+        (false, Err(_)) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDESCREEN: f32 = 16.0 / 9.0;
+
+    #[test]
+    fn wider_window_gets_pillarbox_bars() {
+        let viewport = letterbox_viewport(UVec2::new(2560, 1080), WIDESCREEN).unwrap();
+        assert_eq!(viewport.physical_size, UVec2::new(1920, 1080));
+        assert_eq!(viewport.physical_position, UVec2::new(320, 0));
+    }
+
+    #[test]
+    fn taller_window_gets_letterbox_bars() {
+        let viewport = letterbox_viewport(UVec2::new(1280, 1024), WIDESCREEN).unwrap();
+        assert_eq!(viewport.physical_size, UVec2::new(1280, 720));
+        assert_eq!(viewport.physical_position, UVec2::new(0, 152));
+    }
+
+    #[test]
+    fn minimized_window_has_no_viewport() {
+        assert!(letterbox_viewport(UVec2::new(0, 0), WIDESCREEN).is_none());
+    }
+}
@@ -1,83 +1,374 @@
+pub mod disabled;
+pub mod focus_cursor;
+pub mod font_check;
+pub mod gamepad;
+pub mod hold_repeat;
+pub mod letterbox;
 pub mod navigation;
+pub mod scroll;
+pub mod spacing;
+pub mod stepper;
+pub mod telemetry;
+pub mod text;
+pub mod theme_reload;
+pub mod theme_switch;
+pub mod toast;
+pub mod tooltip;
 
 use crate::plugins::core::{GlobalAction, GlobalInputController};
+use crate::plugins::ui::components::{BackButton, Focused};
+use crate::plugins::ui::input_context::{InputContext, InputContextStack};
+use crate::plugins::ui::navigation::NavigationGraph;
 use crate::plugins::ui::overlays::{
-    OverlayAction, OverlayBackgroundMarker, OverlayMessage, cleanup_overlay_background,
-    setup_overlay_background,
+    OverlayAction, OverlayBackgroundMarker, OverlayChange, OverlayChanged, OverlayMessage,
+    cleanup_overlay_background, setup_overlay_background,
 };
-use crate::plugins::ui::resources::MenuStack;
+use crate::plugins::ui::resources::{CoveredNavigation, MenuBackStack, MenuStack};
 use crate::state::{GameState, OverlayState};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
 
+/// MenuStack together with the state, background and messages that change with it
+#[derive(SystemParam)]
+pub struct OverlayStackControl<'w, 's> {
+    commands: Commands<'w, 's>,
+    overlay_state: ResMut<'w, NextState<OverlayState>>,
+    menu_stack: ResMut<'w, MenuStack>,
+    q_overlay_bg: Query<'w, 's, Entity, With<OverlayBackgroundMarker>>,
+    mw_changed: MessageWriter<'w, OverlayChanged>,
+}
+
+impl OverlayStackControl<'_, '_> {
+    fn changed(&mut self, overlay: OverlayState, change: OverlayChange) {
+        self.mw_changed.write(OverlayChanged { overlay, change });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.menu_stack.is_empty()
+    }
+
+    /// Open an overlay on top, covering the overlay or screen beneath
+    pub fn push(&mut self, overlay: OverlayState) {
+        if overlay == OverlayState::None {
+            warn!("Ignoring push of OverlayState::None");
+            return;
+        }
+
+        let covered = self
+            .menu_stack
+            .peek()
+            .copied()
+            .unwrap_or(OverlayState::None);
+        if self.menu_stack.is_empty() {
+            setup_overlay_background(&mut self.commands);
+        }
+        self.menu_stack.push(overlay);
+        self.overlay_state.set(overlay);
+        info!("Opening overlay {:?} over {:?}", overlay, covered);
+        self.changed(covered, OverlayChange::Covered);
+        self.changed(overlay, OverlayChange::Opened);
+    }
+
+    /// Close the top overlay, revealing the one beneath
+    pub fn pop(&mut self) {
+        let Some(closed) = self.menu_stack.pop() else {
+            return;
+        };
+        self.changed(closed, OverlayChange::Closed);
+
+        let revealed = self
+            .menu_stack
+            .peek()
+            .copied()
+            .unwrap_or(OverlayState::None);
+        if revealed == OverlayState::None {
+            info!("Closing all menus");
+            cleanup_overlay_background(&mut self.commands, &self.q_overlay_bg);
+        } else {
+            info!("Returning to: {:?}", revealed);
+        }
+        self.overlay_state.set(revealed);
+        self.changed(revealed, OverlayChange::Revealed);
+    }
+
+    /// Close the top overlay and open another at the same level
+    ///
+    /// Behaves like `push` on an empty stack, and like `pop` for `OverlayState::None`.
+    pub fn replace(&mut self, overlay: OverlayState) {
+        if overlay == OverlayState::None {
+            self.pop();
+            return;
+        }
+        if self.menu_stack.is_empty() {
+            self.push(overlay);
+            return;
+        }
+
+        let replaced = self.menu_stack.replace(overlay);
+        info!("Replacing {:?} with {:?}", replaced, overlay);
+        if let Some(replaced) = replaced {
+            self.changed(replaced, OverlayChange::Closed);
+        }
+        self.overlay_state.set(overlay);
+        self.changed(overlay, OverlayChange::Opened);
+    }
+}
+
 pub fn menu_stack_control_system(
-    mut commands: Commands,
+    mut overlays: OverlayStackControl,
     game_state: Res<State<GameState>>,
-    mut overlay_state: ResMut<NextState<OverlayState>>,
-    mut menu_stack: ResMut<MenuStack>,
-    q_overlay_bg: Query<Entity, With<OverlayBackgroundMarker>>,
     q_action: Query<&ActionState<GlobalAction>, With<GlobalInputController>>,
     mut mr_overlay: MessageReader<OverlayMessage>,
+    input_context: Res<InputContextStack>,
 ) {
     let action_state = q_action.single();
+    // 对话框自行处理Escape，不穿透到下层菜单
     if let Ok(action_state) = action_state
+        && !input_context.is_active(InputContext::Dialog)
         && action_state.just_pressed(&GlobalAction::ToggleMenu)
     {
-        if menu_stack.is_empty() {
+        if overlays.is_empty() {
             // 空栈，根据状态决定打开何种菜单
             let new_state = match game_state.get() {
                 GameState::MainMenu => OverlayState::ExitMenu,
                 GameState::Match3 => OverlayState::PauseMenu,
             };
-            menu_stack.push(new_state);
-            overlay_state.set(new_state);
-            info!("Opening menu: {:?}", new_state);
-            setup_overlay_background(&mut commands);
+            overlays.push(new_state);
         } else {
             // 有状态就弹出
-            try_pop_menu(
-                &mut commands,
-                &mut overlay_state,
-                &mut menu_stack,
-                q_overlay_bg,
-            );
+            overlays.pop();
         }
     }
     for message in mr_overlay.read() {
         match message.action {
-            OverlayAction::Push => {
-                if menu_stack.is_empty() {
-                    setup_overlay_background(&mut commands)
+            OverlayAction::Push => overlays.push(message.overlay),
+            OverlayAction::Replace => overlays.replace(message.overlay),
+            OverlayAction::Pop => overlays.pop(),
+        }
+    }
+}
+
+/// System that sets aside the navigation of a covered overlay (or screen)
+/// and restores it, focus included, once that overlay is revealed again
+///
+/// Runs before OverlayLifecycle so a newly opened overlay builds its own graph
+/// from scratch.
+pub fn overlay_navigation_system(
+    mut commands: Commands,
+    mut reader: MessageReader<OverlayChanged>,
+    mut nav_graph: ResMut<NavigationGraph>,
+    mut covered: ResMut<CoveredNavigation>,
+    q_focused: Query<Entity, With<Focused>>,
+) {
+    for message in reader.read() {
+        match message.change {
+            OverlayChange::Covered => {
+                for entity in &q_focused {
+                    commands.entity(entity).remove::<Focused>();
                 }
-                menu_stack.push(message.overlay);
-                overlay_state.set(message.overlay);
+                covered.push(std::mem::take(&mut *nav_graph));
             }
-            OverlayAction::Pop => {
-                try_pop_menu(
-                    &mut commands,
-                    &mut overlay_state,
-                    &mut menu_stack,
-                    q_overlay_bg,
-                );
+            OverlayChange::Revealed => {
+                let Some(graph) = covered.pop() else {
+                    continue;
+                };
+                if let Some(focused) = graph.get_focused() {
+                    commands.entity(focused).try_insert(Focused);
+                }
+                *nav_graph = graph;
             }
+            OverlayChange::Opened | OverlayChange::Closed => {}
         }
     }
 }
 
-fn try_pop_menu(
-    commands: &mut Commands,
-    overlay_state: &mut ResMut<NextState<OverlayState>>,
-    menu_stack: &mut ResMut<MenuStack>,
-    q_overlay_bg: Query<Entity, With<OverlayBackgroundMarker>>,
+/// System that records entered GameState screens into the MenuBackStack
+pub fn record_screen_history_system(
+    mut back_stack: ResMut<MenuBackStack>,
+    mut transitions: MessageReader<StateTransitionEvent<GameState>>,
 ) {
-    if menu_stack.pop().is_some() {
-        if let Some(next_state) = menu_stack.peek() {
-            overlay_state.set(*next_state);
-            info!("Returning to: {:?}", next_state);
-        } else {
-            overlay_state.set(OverlayState::None);
-            info!("Closing all menus");
-            cleanup_overlay_background(commands, q_overlay_bg);
+    for transition in transitions.read() {
+        if let Some(entered) = transition.entered {
+            back_stack.push(entered);
         }
     }
 }
+
+/// System handling the Back action and BackButton presses: unwinds one
+/// overlay, or else one screen
+pub fn back_navigation_system(
+    q_action: Query<&ActionState<GlobalAction>, With<GlobalInputController>>,
+    q_back_buttons: Query<&Interaction, (Changed<Interaction>, With<BackButton>)>,
+    menu_stack: Res<MenuStack>,
+    mut back_stack: ResMut<MenuBackStack>,
+    mut ns_game: ResMut<NextState<GameState>>,
+    mut mw_overlay: MessageWriter<OverlayMessage>,
+) {
+    let action_pressed = q_action
+        .single()
+        .is_ok_and(|action_state| action_state.just_pressed(&GlobalAction::Back));
+    let button_pressed = q_back_buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed);
+    if !action_pressed && !button_pressed {
+        return;
+    }
+
+    if let Some(&overlay) = menu_stack.peek() {
+        mw_overlay.write(OverlayMessage {
+            action: OverlayAction::Pop,
+            overlay,
+        });
+    } else if let Some(previous) = back_stack.back() {
+        info!("Back to screen: {:?}", previous);
+        ns_game.set(previous);
+    } else {
+        debug!("Back pressed at root screen, ignoring");
+    }
+}
+
+/// System that keeps the base input context in sync with the current screen
+pub fn sync_input_context_system(
+    game_state: Res<State<GameState>>,
+    menu_stack: Res<MenuStack>,
+    mut input_context: ResMut<InputContextStack>,
+) {
+    let base = match game_state.get() {
+        GameState::Match3 if menu_stack.is_empty() => InputContext::Gameplay,
+        _ => InputContext::Menu,
+    };
+    input_context.set_base(base);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::ui::navigation::NavigationNeighbors;
+    use crate::plugins::ui::testing::minimal_ui_app;
+
+    /// Stand-in for an overlay's UI, spawned and despawned like the real ones
+    #[derive(Component)]
+    struct TestOverlayUi(OverlayState);
+
+    fn test_overlay_lifecycle(
+        mut commands: Commands,
+        mut reader: MessageReader<OverlayChanged>,
+        mut nav_graph: ResMut<NavigationGraph>,
+        q_ui: Query<(Entity, &TestOverlayUi)>,
+    ) {
+        for message in reader.read() {
+            match message.change {
+                OverlayChange::Opened => {
+                    let button = commands
+                        .spawn((Button, TestOverlayUi(message.overlay), Focused))
+                        .id();
+                    nav_graph.clear();
+                    nav_graph.register_button(button, NavigationNeighbors::new());
+                    nav_graph.set_focus(button);
+                }
+                OverlayChange::Closed => {
+                    for (entity, ui) in &q_ui {
+                        if ui.0 == message.overlay {
+                            commands.entity(entity).despawn();
+                        }
+                    }
+                }
+                OverlayChange::Covered | OverlayChange::Revealed => {}
+            }
+        }
+    }
+
+    fn overlay_app() -> App {
+        let mut app = minimal_ui_app();
+        app.add_systems(
+            Update,
+            (
+                menu_stack_control_system,
+                overlay_navigation_system,
+                test_overlay_lifecycle,
+            )
+                .chain(),
+        );
+        app
+    }
+
+    fn send(app: &mut App, action: OverlayAction, overlay: OverlayState) {
+        app.world_mut()
+            .write_message(OverlayMessage { action, overlay });
+        app.update();
+    }
+
+    fn overlay_ui(app: &mut App, overlay: OverlayState) -> Option<Entity> {
+        app.world_mut()
+            .query::<(Entity, &TestOverlayUi)>()
+            .iter(app.world())
+            .find(|(_, ui)| ui.0 == overlay)
+            .map(|(entity, _)| entity)
+    }
+
+    fn background_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<Entity, With<OverlayBackgroundMarker>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn push_push_pop_keeps_the_lower_overlay_and_restores_its_focus() {
+        let mut app = overlay_app();
+
+        send(&mut app, OverlayAction::Push, OverlayState::Settings);
+        let settings = overlay_ui(&mut app, OverlayState::Settings).expect("settings spawned");
+        assert!(app.world().get::<Focused>(settings).is_some());
+
+        send(&mut app, OverlayAction::Push, OverlayState::Alert);
+        let alert = overlay_ui(&mut app, OverlayState::Alert).expect("alert spawned");
+        assert_eq!(overlay_ui(&mut app, OverlayState::Settings), Some(settings));
+        assert!(app.world().get::<Focused>(settings).is_none());
+        assert!(app.world().get::<Focused>(alert).is_some());
+        assert_eq!(app.world().resource::<MenuStack>().depth(), 2);
+        assert_eq!(background_count(&mut app), 1);
+
+        send(&mut app, OverlayAction::Pop, OverlayState::Alert);
+        assert_eq!(overlay_ui(&mut app, OverlayState::Alert), None);
+        assert_eq!(overlay_ui(&mut app, OverlayState::Settings), Some(settings));
+        assert!(app.world().get::<Focused>(settings).is_some());
+        assert_eq!(
+            app.world().resource::<NavigationGraph>().get_focused(),
+            Some(settings)
+        );
+        assert_eq!(
+            app.world().resource::<MenuStack>().peek(),
+            Some(&OverlayState::Settings)
+        );
+
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<OverlayState>>().get(),
+            OverlayState::Settings
+        );
+    }
+
+    #[test]
+    fn replace_swaps_the_top_overlay() {
+        let mut app = overlay_app();
+
+        send(&mut app, OverlayAction::Push, OverlayState::Settings);
+        send(&mut app, OverlayAction::Replace, OverlayState::PauseMenu);
+
+        assert_eq!(overlay_ui(&mut app, OverlayState::Settings), None);
+        let pause = overlay_ui(&mut app, OverlayState::PauseMenu).expect("pause menu spawned");
+        assert!(app.world().get::<Focused>(pause).is_some());
+        let menu_stack = app.world().resource::<MenuStack>();
+        assert_eq!(menu_stack.depth(), 1);
+        assert_eq!(menu_stack.peek(), Some(&OverlayState::PauseMenu));
+        assert_eq!(background_count(&mut app), 1);
+
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<OverlayState>>().get(),
+            OverlayState::PauseMenu
+        );
+    }
+}
@@ -1,17 +1,81 @@
 use crate::plugins::core::{MenuNavigationAction, MenuNavigationInputController};
 use crate::plugins::ui::button_builder::ButtonStyle;
-use crate::plugins::ui::components::{Focusable, Focused, Selected};
-use crate::plugins::ui::navigation::{Direction, NavigationGraph};
+use crate::plugins::ui::components::{Disabled, FocusRing, Focusable, Focused, NavigationBump};
+use crate::plugins::ui::navigation::{
+    ActivationKeys, Direction, NavigationBumped, NavigationConfig, NavigationEvent,
+    NavigationGraph,
+};
+use crate::plugins::ui::resources::AccessibilitySettings;
+use crate::plugins::ui::stepper::Stepper;
+use crate::plugins::ui::styles::Theme;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use leafwing_input_manager::action_state::ActionState;
 
+/// Shared focus movement used by keyboard and gamepad navigation
+#[derive(SystemParam)]
+pub struct FocusMover<'w, 's> {
+    commands: Commands<'w, 's>,
+    nav_graph: ResMut<'w, NavigationGraph>,
+    q_focused: Query<'w, 's, Entity, With<Focused>>,
+    q_focusable: Query<'w, 's, Entity, (With<Focusable>, Without<Disabled>)>,
+    q_disabled: Query<'w, 's, (), With<Disabled>>,
+    q_steppers: Query<'w, 's, (), With<Stepper>>,
+    mw_bumped: MessageWriter<'w, NavigationBumped>,
+}
+
+impl FocusMover<'_, '_> {
+    /// Move focus one step in a direction, or focus the first button if none is focused
+    pub fn move_focus(&mut self, dir: Direction) {
+        // Get the current focused button
+        let Some(current) = self.q_focused.iter().next() else {
+            // No button is focused, focus the first focusable button
+            if let Some(first_button) = self.q_focusable.iter().next() {
+                self.commands.entity(first_button).insert(Focused);
+                self.nav_graph.set_focus(first_button);
+                info!("Focused first button {:?}", first_button);
+            }
+            return;
+        };
+
+        // Try to navigate to a neighbor, skipping disabled ones
+        // (bounded so a cycle of disabled buttons can't loop forever)
+        let mut target = self.nav_graph.get_neighbor(current, dir);
+        for _ in 0..self.q_disabled.iter().count() {
+            match target {
+                Some(candidate) if self.q_disabled.contains(candidate) => {
+                    target = self.nav_graph.get_neighbor(candidate, dir);
+                }
+                _ => break,
+            }
+        }
+        let target = target.filter(|&entity| !self.q_disabled.contains(entity));
+
+        if let Some(neighbor) = target {
+            // Remove focus from current
+            self.commands.entity(current).remove::<Focused>();
+            // Add focus to neighbor
+            self.commands.entity(neighbor).insert(Focused);
+            self.nav_graph.set_focus(neighbor);
+            info!("Navigated {:?} to button {:?}", dir, neighbor);
+        } else {
+            // 步进器用左右调整数值，不算撞到边缘
+            let adjusts_stepper = matches!(dir, Direction::Left | Direction::Right)
+                && self.q_steppers.contains(current);
+            if !adjusts_stepper {
+                self.mw_bumped.write(NavigationBumped {
+                    entity: current,
+                    direction: dir,
+                });
+            }
+        }
+    }
+}
+
 /// System to handle directional navigation input and update focus
 pub fn handle_navigation_input(
     q_action: Query<&ActionState<MenuNavigationAction>, With<MenuNavigationInputController>>,
-    mut nav_graph: ResMut<NavigationGraph>,
-    mut commands: Commands,
-    q_focused: Query<Entity, With<Focused>>,
-    q_focusable: Query<Entity, With<Focusable>>,
+    mut focus: FocusMover,
 ) {
     let action_state = q_action.single();
     let Ok(action_state) = action_state else {
@@ -19,74 +83,230 @@ pub fn handle_navigation_input(
     };
 
     let direction = if action_state.just_pressed(&MenuNavigationAction::Up) {
-        Some(Direction::Up)
+        Direction::Up
     } else if action_state.just_pressed(&MenuNavigationAction::Down) {
-        Some(Direction::Down)
+        Direction::Down
     } else if action_state.just_pressed(&MenuNavigationAction::Left) {
-        Some(Direction::Left)
+        Direction::Left
     } else if action_state.just_pressed(&MenuNavigationAction::Right) {
-        Some(Direction::Right)
+        Direction::Right
     } else {
         return;
     };
     debug!("direction: {:?}", direction);
 
-    if let Some(dir) = direction {
-        // Get the current focused button
-        let current_focused = q_focused.iter().next();
-
-        if let Some(current) = current_focused {
-            // Try to navigate to a neighbor
-            if let Some(neighbor) = nav_graph.get_neighbor(current, dir) {
-                // Remove focus from current
-                commands.entity(current).remove::<Focused>();
-                // Add focus to neighbor
-                commands.entity(neighbor).insert(Focused);
-                nav_graph.set_focus(neighbor);
-                info!("Navigated {:?} to button {:?}", dir, neighbor);
-            }
+    focus.move_focus(direction);
+}
+
+/// Direction currently held on a gamepad, D-pad first, then the left stick
+fn held_gamepad_direction(gamepad: &Gamepad, threshold: f32) -> Option<Direction> {
+    let dpad = [
+        (GamepadButton::DPadUp, Direction::Up),
+        (GamepadButton::DPadDown, Direction::Down),
+        (GamepadButton::DPadLeft, Direction::Left),
+        (GamepadButton::DPadRight, Direction::Right),
+    ];
+    if let Some(&(_, dir)) = dpad.iter().find(|(button, _)| gamepad.pressed(*button)) {
+        return Some(dir);
+    }
+
+    let stick = gamepad.left_stick();
+    if stick.length() < threshold {
+        return None;
+    }
+    // 取主轴方向，手柄Y轴向上为正
+    Some(if stick.x.abs() > stick.y.abs() {
+        if stick.x > 0.0 {
+            Direction::Right
         } else {
-            // No button is focused, focus the first focusable button
-            if let Some(first_button) = q_focusable.iter().next() {
-                commands.entity(first_button).insert(Focused);
-                nav_graph.set_focus(first_button);
-                info!("Focused first button {:?}", first_button);
-            }
+            Direction::Left
         }
+    } else if stick.y > 0.0 {
+        Direction::Up
+    } else {
+        Direction::Down
+    })
+}
+
+/// Held direction and time until it repeats, for gamepad navigation
+#[derive(Default)]
+pub struct GamepadRepeat {
+    direction: Option<Direction>,
+    until_repeat: f32,
+}
+
+/// System that moves focus with the gamepad D-pad and left stick
+///
+/// A new direction moves once immediately; holding it repeats after
+/// `NavigationConfig::repeat_delay`, then every `repeat_interval`, so a held
+/// stick never skips more than one button per step.
+pub fn gamepad_navigation_system(
+    time: Res<Time>,
+    config: Res<NavigationConfig>,
+    q_gamepads: Query<&Gamepad>,
+    mut repeat: Local<GamepadRepeat>,
+    mut focus: FocusMover,
+) {
+    let held = q_gamepads
+        .iter()
+        .find_map(|gamepad| held_gamepad_direction(gamepad, config.stick_threshold));
+
+    let Some(direction) = held else {
+        repeat.direction = None;
+        return;
+    };
+
+    if repeat.direction != Some(direction) {
+        repeat.direction = Some(direction);
+        repeat.until_repeat = config.repeat_delay;
+        focus.move_focus(direction);
+        return;
+    }
+
+    repeat.until_repeat -= time.delta_secs();
+    if repeat.until_repeat <= 0.0 {
+        repeat.until_repeat += config.repeat_interval;
+        focus.move_focus(direction);
+    }
+}
+
+/// System that writes a NavigationEvent when the graph's focus changes
+///
+/// Watching the graph covers every path that moves focus (keyboard, gamepad,
+/// menu setup); several changes within one frame report only the last one.
+pub fn emit_navigation_event_system(
+    nav_graph: Res<NavigationGraph>,
+    mut last_focused: Local<Option<Entity>>,
+    mut mw_navigation: MessageWriter<NavigationEvent>,
+) {
+    let focused = nav_graph.get_focused();
+    if focused == *last_focused {
+        return;
     }
+
+    if let Some(to) = focused {
+        mw_navigation.write(NavigationEvent {
+            from: *last_focused,
+            to,
+        });
+    }
+    *last_focused = focused;
 }
 
-/// Universal button style system that handles all button visual states
-/// Priority: Pressed > Hovered > Focused > Selected > Normal
-pub fn universal_button_style_system(
-    mut q_buttons: Query<
-        (
-            &mut BackgroundColor,
-            &Interaction,
-            Option<&Focused>,
-            Option<&Selected>,
-        ),
-        With<Button>,
+/// System that presses the focused button when an activation key is pressed
+///
+/// The button's Interaction is set to Pressed for one frame, so the existing
+/// `Changed<Interaction>` action handlers fire unchanged. Runs in PreUpdate
+/// after UI focus so every Update system sees the press, and releases the
+/// button again on the next frame.
+pub fn activate_focused_button_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    activation: Res<ActivationKeys>,
+    mut q_focused: Query<
+        (Entity, &mut Interaction),
+        (With<Focused>, With<Button>, Without<Disabled>),
     >,
-    button_style: Res<ButtonStyleResource>,
+    mut q_interaction: Query<&mut Interaction, Without<Focused>>,
+    mut pressed: Local<Option<Entity>>,
 ) {
-    for (mut bg_color, interaction, focused, selected) in q_buttons.iter_mut() {
-        let new_color = match *interaction {
-            Interaction::Pressed => button_style.0.pressed,
-            Interaction::Hovered => button_style.0.hovered,
-            Interaction::None => {
-                // Check for focused or selected states
-                if focused.is_some() {
-                    button_style.0.focused
-                } else if selected.is_some() {
-                    button_style.0.selected
-                } else {
-                    button_style.0.normal
-                }
-            }
+    // 释放上一帧合成的按下状态
+    if let Some(entity) = pressed.take() {
+        let interaction = match q_focused.get_mut(entity) {
+            Ok((_, interaction)) => Some(interaction),
+            Err(_) => q_interaction.get_mut(entity).ok(),
         };
+        if let Some(mut interaction) = interaction
+            && *interaction == Interaction::Pressed
+        {
+            *interaction = Interaction::None;
+        }
+    }
+
+    if !activation.just_pressed(&keyboard, &gamepads) {
+        return;
+    }
 
-        *bg_color = new_color.into();
+    if let Ok((entity, mut interaction)) = q_focused.single_mut() {
+        debug!("Activating focused button {:?}", entity);
+        *interaction = Interaction::Pressed;
+        *pressed = Some(entity);
+    }
+}
+
+/// Duration and distance of the navigation edge bump
+const BUMP_DURATION_SECS: f32 = 0.15;
+const BUMP_DISTANCE: f32 = 6.0;
+
+/// System that starts a bump animation on the element that hit a navigation edge
+pub fn start_navigation_bump_system(
+    mut commands: Commands,
+    settings: Res<AccessibilitySettings>,
+    mut reader: MessageReader<NavigationBumped>,
+) {
+    for bumped in reader.read() {
+        debug!("Navigation edge reached {:?} at {:?}", bumped.direction, bumped.entity);
+        if settings.reduced_motion {
+            continue;
+        }
+        commands.entity(bumped.entity).insert(NavigationBump {
+            direction: bumped.direction.as_vec2(),
+            timer: Timer::from_seconds(BUMP_DURATION_SECS, TimerMode::Once),
+        });
+    }
+}
+
+/// System that nudges bumped elements toward the blocked direction and back
+pub fn navigation_bump_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_bumps: Query<(Entity, &mut NavigationBump, &mut UiTransform)>,
+) {
+    for (entity, mut bump, mut transform) in q_bumps.iter_mut() {
+        bump.timer.tick(time.delta());
+        if bump.timer.is_finished() {
+            transform.translation = Val2::px(0.0, 0.0);
+            commands.entity(entity).remove::<NavigationBump>();
+            continue;
+        }
+
+        let offset = bump.direction
+            * BUMP_DISTANCE
+            * (bump.timer.fraction() * std::f32::consts::PI).sin();
+        transform.translation = Val2::px(offset.x, offset.y);
+    }
+}
+
+/// Width of the focus ring outline in pixels
+const FOCUS_RING_WIDTH: f32 = 2.0;
+
+/// System that outlines the focused element when the focus ring is enabled
+///
+/// The outline follows the element's size and moves with the Focused marker.
+pub fn focus_ring_system(
+    mut commands: Commands,
+    settings: Res<AccessibilitySettings>,
+    theme: Res<Theme>,
+    q_focused: Query<Entity, (With<Focused>, Without<FocusRing>)>,
+    q_rings: Query<(Entity, Has<Focused>), With<FocusRing>>,
+) {
+    if settings.focus_ring {
+        for entity in q_focused.iter() {
+            commands.entity(entity).insert((
+                Outline::new(
+                    Val::Px(FOCUS_RING_WIDTH),
+                    Val::Px(FOCUS_RING_WIDTH),
+                    theme.colors.text_primary,
+                ),
+                FocusRing,
+            ));
+        }
+    }
+
+    for (entity, focused) in q_rings.iter() {
+        if !settings.focus_ring || !focused {
+            commands.entity(entity).remove::<(Outline, FocusRing)>();
+        }
     }
 }
 
@@ -103,3 +323,37 @@ pub fn cleanup_despawned_buttons(
         nav_graph.remove_button(entity);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::ui::button_builder::{ButtonNavigationBuilder, NavigationLayout};
+    use crate::plugins::ui::testing::{
+        build_navigation, minimal_ui_app, press, spawn_buttons, spawn_navigation_controller,
+    };
+
+    #[test]
+    fn arrow_down_moves_focused_to_next_button() {
+        let mut app = minimal_ui_app();
+        app.add_systems(Update, handle_navigation_input);
+        let controller = spawn_navigation_controller(&mut app);
+
+        let buttons = spawn_buttons(&mut app, 2);
+        let mut builder = ButtonNavigationBuilder::new(NavigationLayout::Vertical);
+        for &button in &buttons {
+            builder.add_button(button);
+        }
+        build_navigation(&mut app, builder, true);
+        assert!(app.world().get::<Focused>(buttons[0]).is_some());
+
+        press(&mut app, controller, MenuNavigationAction::Down);
+        app.update();
+
+        assert!(app.world().get::<Focused>(buttons[0]).is_none());
+        assert!(app.world().get::<Focused>(buttons[1]).is_some());
+        assert_eq!(
+            app.world().resource::<NavigationGraph>().get_focused(),
+            Some(buttons[1])
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use crate::plugins::ui::components::{Focused, ScrollAxis, Scrollable};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+/// Pixels scrolled per wheel line
+const SCROLL_LINE_HEIGHT: f32 = 24.0;
+/// Part of the visible height scrolled by PageUp/PageDown, keeping some context
+const SCROLL_PAGE_FRACTION: f32 = 0.9;
+
+/// System that scrolls the hovered Scrollable with the mouse wheel
+///
+/// The upper bound is left to the UI layout, which clamps the scroll
+/// position to the content size.
+pub fn scroll_wheel_system(
+    mut reader: MessageReader<MouseWheel>,
+    mut q_scrollables: Query<(&Scrollable, &RelativeCursorPosition, &mut ScrollPosition)>,
+) {
+    let delta: f32 = reader
+        .read()
+        .map(|wheel| match wheel.unit {
+            MouseScrollUnit::Line => wheel.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => wheel.y,
+        })
+        .sum();
+    if delta == 0.0 {
+        return;
+    }
+
+    for (scrollable, cursor, mut scroll) in q_scrollables.iter_mut() {
+        if !cursor.cursor_over() {
+            continue;
+        }
+        match scrollable.axis {
+            ScrollAxis::Vertical => scroll.y = (scroll.y - delta).max(0.0),
+        }
+    }
+}
+
+/// System that pages Scrollable containers with PageUp/PageDown
+///
+/// Containers holding the focused element take priority; without one, every
+/// Scrollable on screen is paged.
+pub fn scroll_keyboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    q_focused: Query<Entity, With<Focused>>,
+    q_parents: Query<&ChildOf>,
+    mut q_scrollables: Query<(Entity, &Scrollable, &ComputedNode, &mut ScrollPosition)>,
+) {
+    let direction = if keyboard.just_pressed(KeyCode::PageDown) {
+        1.0
+    } else if keyboard.just_pressed(KeyCode::PageUp) {
+        -1.0
+    } else {
+        return;
+    };
+
+    let focused_ancestors: Vec<Entity> = q_focused
+        .iter()
+        .flat_map(|entity| q_parents.iter_ancestors(entity))
+        .filter(|&ancestor| q_scrollables.contains(ancestor))
+        .collect();
+
+    for (entity, scrollable, computed, mut scroll) in q_scrollables.iter_mut() {
+        if !focused_ancestors.is_empty() && !focused_ancestors.contains(&entity) {
+            continue;
+        }
+        let visible = computed.size() * computed.inverse_scale_factor();
+        match scrollable.axis {
+            ScrollAxis::Vertical => {
+                let page = visible.y * SCROLL_PAGE_FRACTION;
+                scroll.y = (scroll.y + direction * page).max(0.0);
+            }
+        }
+    }
+}
+
+/// System that scrolls a newly focused element into view
+///
+/// Only the nearest Scrollable ancestor is adjusted, by the smallest amount
+/// that makes the element fully visible.
+pub fn scroll_focus_into_view_system(
+    q_focused: Query<(Entity, &ComputedNode, &UiGlobalTransform), Added<Focused>>,
+    q_parents: Query<&ChildOf>,
+    mut q_scrollables: Query<(
+        &Scrollable,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &mut ScrollPosition,
+    )>,
+) {
+    for (entity, computed, transform) in q_focused.iter() {
+        let Some(container) = q_parents
+            .iter_ancestors(entity)
+            .find(|&ancestor| q_scrollables.contains(ancestor))
+        else {
+            continue;
+        };
+        let Ok((scrollable, container_node, container_transform, mut scroll)) =
+            q_scrollables.get_mut(container)
+        else {
+            continue;
+        };
+
+        // 刚生成的节点尚未完成排版
+        if computed.size() == Vec2::ZERO || container_node.size() == Vec2::ZERO {
+            continue;
+        }
+
+        let scale = computed.inverse_scale_factor();
+        let target = Rect::from_center_size(transform.translation * scale, computed.size() * scale);
+        let view = Rect::from_center_size(
+            container_transform.translation * scale,
+            container_node.size() * scale,
+        );
+
+        match scrollable.axis {
+            ScrollAxis::Vertical => {
+                if target.min.y < view.min.y {
+                    scroll.y = (scroll.y - (view.min.y - target.min.y)).max(0.0);
+                } else if target.max.y > view.max.y {
+                    scroll.y += target.max.y - view.max.y;
+                }
+            }
+        }
+    }
+}
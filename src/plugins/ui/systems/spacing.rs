@@ -0,0 +1,30 @@
+use crate::plugins::ui::components::SpacingRoles;
+use crate::plugins::ui::styles::Theme;
+use bevy::prelude::*;
+
+/// System that re-applies themed padding, margins and gaps when the Theme changes
+///
+/// Only containers built with the `*_themed` ContainerBuilder methods carry
+/// SpacingRoles; raw pixel values are left untouched.
+pub fn apply_spacing_scale_system(
+    theme: Res<Theme>,
+    mut q_nodes: Query<(&SpacingRoles, &mut Node)>,
+) {
+    if !theme.is_changed() {
+        return;
+    }
+
+    for (roles, mut node) in q_nodes.iter_mut() {
+        if let Some(padding) = roles.padding {
+            node.padding = UiRect::all(px(padding.get_value(&theme.spacing)));
+        }
+        if let Some(margin) = roles.margin {
+            node.margin = UiRect::all(px(margin.get_value(&theme.spacing)));
+        }
+        if let Some(gap) = roles.gap {
+            let value = px(gap.get_value(&theme.spacing));
+            node.row_gap = value;
+            node.column_gap = value;
+        }
+    }
+}
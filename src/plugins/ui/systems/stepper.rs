@@ -0,0 +1,103 @@
+use crate::plugins::core::{MenuNavigationAction, MenuNavigationInputController};
+use crate::plugins::ui::components::Focused;
+use crate::plugins::ui::stepper::{
+    Stepper, StepperButtonAction, StepperChanged, StepperValueLabel,
+};
+use crate::plugins::ui::styles::Theme;
+use bevy::prelude::*;
+use leafwing_input_manager::action_state::ActionState;
+
+/// System handling presses on the − / + buttons of steppers
+pub fn stepper_button_system(
+    q_interaction: Query<
+        (&Interaction, &StepperButtonAction, &ChildOf),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut q_steppers: Query<&mut Stepper>,
+    mut mw_changed: MessageWriter<StepperChanged>,
+) {
+    for (interaction, action, child_of) in &q_interaction {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let stepper_entity = child_of.parent();
+        let Ok(mut stepper) = q_steppers.get_mut(stepper_entity) else {
+            continue;
+        };
+
+        let steps = match action {
+            StepperButtonAction::Decrease => -1.0,
+            StepperButtonAction::Increase => 1.0,
+        };
+        if stepper.step_by(steps) {
+            mw_changed.write(StepperChanged {
+                stepper: stepper_entity,
+                value: stepper.value,
+            });
+        }
+    }
+}
+
+/// System adjusting the focused stepper with Left/Right
+pub fn stepper_keyboard_system(
+    q_action: Query<&ActionState<MenuNavigationAction>, With<MenuNavigationInputController>>,
+    mut q_steppers: Query<(Entity, &mut Stepper), With<Focused>>,
+    mut mw_changed: MessageWriter<StepperChanged>,
+) {
+    let Ok(action_state) = q_action.single() else {
+        return;
+    };
+
+    let steps = if action_state.just_pressed(&MenuNavigationAction::Left) {
+        -1.0
+    } else if action_state.just_pressed(&MenuNavigationAction::Right) {
+        1.0
+    } else {
+        return;
+    };
+
+    for (entity, mut stepper) in q_steppers.iter_mut() {
+        if stepper.step_by(steps) {
+            mw_changed.write(StepperChanged {
+                stepper: entity,
+                value: stepper.value,
+            });
+        }
+    }
+}
+
+/// System refreshing the value label and bound styling of changed steppers
+pub fn update_stepper_display_system(
+    theme: Res<Theme>,
+    q_steppers: Query<(&Stepper, &Children), Changed<Stepper>>,
+    q_buttons: Query<(&StepperButtonAction, &Children)>,
+    mut q_labels: Query<&mut Text, With<StepperValueLabel>>,
+    mut q_text_colors: Query<&mut TextColor>,
+) {
+    for (stepper, children) in q_steppers.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = q_labels.get_mut(child) {
+                *text = Text::new(stepper.label());
+            }
+
+            // 到达边界时按钮文字置灰
+            if let Ok((action, button_children)) = q_buttons.get(child) {
+                let at_bound = match action {
+                    StepperButtonAction::Decrease => stepper.at_min(),
+                    StepperButtonAction::Increase => stepper.at_max(),
+                };
+                let color = if at_bound {
+                    theme.colors.text_disabled
+                } else {
+                    theme.colors.text_primary
+                };
+                for button_child in button_children.iter() {
+                    if let Ok(mut text_color) = q_text_colors.get_mut(button_child) {
+                        text_color.0 = color;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::plugins::ui::components::Disabled;
+use crate::plugins::ui::telemetry::{ButtonActionName, ButtonTelemetry, ButtonTelemetryConfig};
+use bevy::prelude::*;
+
+/// System that emits ButtonTelemetry for pressed buttons
+///
+/// Disabled buttons are skipped. `HoldRepeat` re-triggers `Changed<Interaction>`
+/// on every repeat tick, so a held button reports each repeat as its own press;
+/// this is intended, as every repeat fires the button's action again.
+pub fn emit_button_telemetry_system(
+    config: Res<ButtonTelemetryConfig>,
+    time: Res<Time>,
+    q_interaction: Query<
+        (Entity, &Interaction, &ButtonActionName),
+        (Changed<Interaction>, With<Button>, Without<Disabled>),
+    >,
+    mut mw_telemetry: MessageWriter<ButtonTelemetry>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    for (entity, interaction, action_name) in &q_interaction {
+        if *interaction == Interaction::Pressed {
+            mw_telemetry.write(ButtonTelemetry {
+                button: entity,
+                action: action_name.0,
+                timestamp: time.elapsed(),
+            });
+        }
+    }
+}
+
+/// Default subscriber writing button telemetry to the log (game.log in release)
+pub fn log_button_telemetry_system(mut reader: MessageReader<ButtonTelemetry>) {
+    for telemetry in reader.read() {
+        info!(
+            "[telemetry] button {:?} pressed: {} at {:.3}s",
+            telemetry.button,
+            telemetry.action,
+            telemetry.timestamp.as_secs_f64()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::ui::testing::minimal_ui_app;
+
+    fn telemetry_app() -> App {
+        let mut app = minimal_ui_app();
+        app.insert_resource(ButtonTelemetryConfig { enabled: true })
+            .add_message::<ButtonTelemetry>()
+            .add_systems(Update, emit_button_telemetry_system);
+        app
+    }
+
+    #[test]
+    fn pressed_button_emits_telemetry() {
+        let mut app = telemetry_app();
+        app.world_mut().spawn((
+            Button,
+            Interaction::Pressed,
+            ButtonActionName("TestAction"),
+        ));
+
+        app.update();
+
+        let messages = app.world().resource::<Messages<ButtonTelemetry>>();
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn disabled_button_press_emits_nothing() {
+        let mut app = telemetry_app();
+        app.world_mut().spawn((
+            Button,
+            Interaction::Pressed,
+            ButtonActionName("TestAction"),
+            Disabled,
+        ));
+
+        app.update();
+
+        let messages = app.world().resource::<Messages<ButtonTelemetry>>();
+        assert!(messages.is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::plugins::ui::components::{ThemedFontSize, TruncatedText};
+use crate::plugins::ui::styles::Theme;
+use crate::plugins::ui::tooltip::Tooltip;
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+
+/// System that shortens TruncatedText until it fits its max width
+///
+/// Each pass estimates the fitting length from the measured width, so long
+/// text converges within a frame or two. Truncated entries get a Tooltip
+/// with the full text.
+pub fn truncate_text_system(
+    mut commands: Commands,
+    mut q_text: Query<
+        (
+            Entity,
+            &mut TruncatedText,
+            &mut Text,
+            &TextLayoutInfo,
+            &ComputedNode,
+        ),
+        Or<(Changed<TextLayoutInfo>, Changed<TruncatedText>)>,
+    >,
+) {
+    for (entity, mut truncated, mut text, layout, computed) in q_text.iter_mut() {
+        if truncated.is_changed() && !truncated.is_added() {
+            // 完整文本被替换，恢复后等待重新排版
+            truncated.shown_chars = None;
+            text.0 = truncated.full.clone();
+            commands.entity(entity).remove::<Tooltip>();
+            continue;
+        }
+
+        let width = layout.size.x * computed.inverse_scale_factor();
+        if width <= truncated.max_width {
+            continue;
+        }
+
+        let chars = truncated
+            .shown_chars
+            .unwrap_or_else(|| truncated.full.chars().count());
+        if chars == 0 {
+            continue;
+        }
+
+        let estimate = (chars as f32 * truncated.max_width / width) as usize;
+        let next = estimate.min(chars - 1);
+        let shown: String = truncated.full.chars().take(next).collect();
+        text.0 = format!("{shown}…");
+
+        let truncated = truncated.bypass_change_detection();
+        truncated.shown_chars = Some(next);
+        commands
+            .entity(entity)
+            .insert(Tooltip::new(truncated.full.clone()));
+    }
+}
+
+/// System that re-applies themed font sizes when the Theme changes
+///
+/// Lets a change to `typography.scale` rescale text that is already on screen.
+pub fn apply_font_scale_system(
+    theme: Res<Theme>,
+    mut q_text: Query<(&ThemedFontSize, &mut TextFont)>,
+) {
+    if !theme.is_changed() {
+        return;
+    }
+
+    for (role, mut font) in q_text.iter_mut() {
+        let size = role.0.font_size(&theme.typography);
+        if font.font_size != size {
+            font.font_size = size;
+        }
+    }
+}
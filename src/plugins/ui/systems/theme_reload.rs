@@ -0,0 +1,45 @@
+use crate::plugins::ui::styles::Theme;
+use crate::plugins::ui::theme_asset::{THEME_ASSET_PATH, ThemeAsset, ThemeSource};
+use bevy::prelude::*;
+
+/// Startup system that starts loading the theme file
+pub fn load_theme_asset(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ThemeSource(asset_server.load(THEME_ASSET_PATH)));
+}
+
+/// System that replaces the Theme resource when the theme file loads or changes
+///
+/// Only the resource is swapped: `refresh_themed_elements_system` recolours
+/// what is already on screen and systems reacting to `Theme` changes
+/// (spacing, letterbox, font check) update live, so no screen is re-entered
+/// and a running game keeps its state. File edits are only seen with the
+/// `hot-reload` feature, which enables Bevy's file watcher.
+pub fn apply_theme_asset_system(
+    mut reader: MessageReader<AssetEvent<ThemeAsset>>,
+    source: Option<Res<ThemeSource>>,
+    themes: Res<Assets<ThemeAsset>>,
+    mut theme: ResMut<Theme>,
+) {
+    let Some(source) = source else {
+        return;
+    };
+
+    for event in reader.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = *event
+        else {
+            continue;
+        };
+        if id != source.0.id() {
+            continue;
+        }
+        let Some(asset) = themes.get(id) else {
+            continue;
+        };
+
+        match source.0.path() {
+            Some(path) => info!("Applying theme from {}", path),
+            None => info!("Applying theme asset"),
+        }
+        *theme = asset.0.clone();
+    }
+}
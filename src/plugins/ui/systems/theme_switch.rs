@@ -0,0 +1,94 @@
+use crate::plugins::ui::button_builder::{ButtonStyleOverride, ThemedButtonStyle};
+use crate::plugins::ui::components::{ThemedBackground, ThemedBorder, ThemedText};
+use crate::plugins::ui::styles::{Theme, ThemeMessage, ThemeVariant};
+use crate::plugins::ui::systems::navigation::ButtonStyleResource;
+use crate::plugins::ui::theme_asset::{ThemeAsset, ThemeSource};
+use bevy::prelude::*;
+
+/// System that swaps the Theme resource when a ThemeMessage arrives
+///
+/// The built-in palette is applied immediately. When a theme file is in use,
+/// the source is pointed at the variant's file so it takes over once loaded,
+/// or right away if that file was loaded by an earlier switch.
+pub fn switch_theme_system(
+    mut reader: MessageReader<ThemeMessage>,
+    mut theme: ResMut<Theme>,
+    mut variant: ResMut<ThemeVariant>,
+    source: Option<ResMut<ThemeSource>>,
+    asset_server: Res<AssetServer>,
+    themes: Res<Assets<ThemeAsset>>,
+) {
+    let Some(message) = reader.read().last() else {
+        return;
+    };
+
+    info!("Switching theme to {:?}", message.theme);
+    *variant = message.theme;
+    *theme = Theme::variant(message.theme);
+
+    if let Some(mut source) = source {
+        source.0 = asset_server.load(message.theme.asset_path());
+        // 已加载过的文件不会再发出加载事件
+        if let Some(asset) = themes.get(&source.0) {
+            *theme = asset.0.clone();
+        }
+    }
+}
+
+/// System that recolours already-spawned UI elements when the Theme changes
+///
+/// Only entities tagged with a ThemedBackground/ThemedText/ThemedBorder role
+/// are touched; buttons take their background from ButtonStyleResource, or
+/// from their ThemedButtonStyle role. Buttons with a fixed
+/// ButtonStyleOverride keep their style. A new radius of 0.0 removes the
+/// button's BorderRadius, matching how buttons are spawned.
+pub fn refresh_themed_elements_system(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut button_style: ResMut<ButtonStyleResource>,
+    mut q_backgrounds: Query<(&ThemedBackground, &mut BackgroundColor), Without<Button>>,
+    mut q_texts: Query<(&ThemedText, &mut TextColor)>,
+    mut q_borders: Query<(&ThemedBorder, &mut BorderColor)>,
+    mut q_buttons: Query<
+        (
+            Entity,
+            Option<&ThemedButtonStyle>,
+            Option<&mut ButtonStyleOverride>,
+        ),
+        With<Button>,
+    >,
+) {
+    if !theme.is_changed() {
+        return;
+    }
+
+    button_style.0 = theme.button.clone();
+
+    for (role, mut background) in q_backgrounds.iter_mut() {
+        *background = role.0.get_color(&theme.colors).into();
+    }
+    for (role, mut color) in q_texts.iter_mut() {
+        color.0 = role.0.get_color(&theme.colors);
+    }
+    for (role, mut border) in q_borders.iter_mut() {
+        *border = BorderColor::all(role.0.get_color(&theme.colors));
+    }
+    for (entity, role, style_override) in q_buttons.iter_mut() {
+        let border_radius = match (role, style_override) {
+            (Some(role), Some(mut style_override)) => {
+                style_override.0 = role.0.resolve(&theme);
+                style_override.0.border_radius
+            }
+            (_, Some(_)) => continue,
+            (_, None) => theme.button.border_radius,
+        };
+        // 与生成时一致：半径为 0 时不保留 BorderRadius
+        if border_radius > 0.0 {
+            commands
+                .entity(entity)
+                .insert(BorderRadius::all(Val::Px(border_radius)));
+        } else {
+            commands.entity(entity).remove::<BorderRadius>();
+        }
+    }
+}
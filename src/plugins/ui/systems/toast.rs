@@ -0,0 +1,70 @@
+use crate::plugins::ui::fonts::Fonts;
+use crate::plugins::ui::styles::Theme;
+use crate::plugins::ui::toast::{TOAST_DURATION_SECS, Toast, ToastMessage};
+use crate::plugins::ui::ui_builders::{TextLevel, text_styled};
+use bevy::prelude::*;
+
+/// System that spawns a toast for the latest ToastMessage, replacing any visible one
+pub fn spawn_toast_system(
+    mut commands: Commands,
+    mut reader: MessageReader<ToastMessage>,
+    theme: Res<Theme>,
+    fonts: Fonts,
+    q_toasts: Query<Entity, With<Toast>>,
+) {
+    let Some(message) = reader.read().last() else {
+        return;
+    };
+
+    // 同一时间只显示一条提示
+    for entity in q_toasts.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: px(theme.spacing.xl),
+                width: percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ZIndex(10),
+            Pickable::IGNORE,
+            Toast {
+                timer: Timer::from_seconds(TOAST_DURATION_SECS, TimerMode::Once),
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(px(theme.spacing.md), px(theme.spacing.sm)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.colors.surface),
+                ))
+                .with_children(|toast| {
+                    toast.spawn(text_styled(
+                        message.text.clone(),
+                        TextLevel::Small,
+                        &theme,
+                        &fonts,
+                    ));
+                });
+        });
+}
+
+/// System to despawn toasts once they expire
+pub fn update_toast_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_toasts: Query<(Entity, &mut Toast)>,
+) {
+    for (entity, mut toast) in q_toasts.iter_mut() {
+        if toast.timer.tick(time.delta()).just_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
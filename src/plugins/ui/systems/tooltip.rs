@@ -0,0 +1,83 @@
+use crate::plugins::ui::fonts::Fonts;
+use crate::plugins::ui::styles::Theme;
+use crate::plugins::ui::tooltip::{TOOLTIP_CURSOR_OFFSET, Tooltip, TooltipPanel};
+use crate::plugins::ui::ui_builders::{TextLevel, clamp_to_window, text_styled};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// System that shows a tooltip panel next to the cursor for the hovered entity
+///
+/// New panels spawn hidden because their size is unknown until layout runs;
+/// they are clamped into the window and shown once measured.
+pub fn tooltip_system(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    fonts: Fonts,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_hovered: Query<(Entity, &Interaction, &Tooltip)>,
+    mut q_panels: Query<(
+        Entity,
+        &TooltipPanel,
+        &mut Node,
+        &mut Visibility,
+        &ComputedNode,
+    )>,
+) {
+    let Ok(window) = q_window.single() else {
+        return;
+    };
+    let cursor = window.cursor_position();
+
+    let hovered = q_hovered
+        .iter()
+        .find(|(_, interaction, _)| **interaction != Interaction::None);
+    let hovered = cursor.and(hovered);
+
+    let mut has_panel = false;
+    for (panel_entity, panel, mut node, mut visibility, computed) in q_panels.iter_mut() {
+        match (hovered, cursor) {
+            (Some((owner, _, _)), Some(cursor)) if owner == panel.owner => {
+                let size = computed.size() * computed.inverse_scale_factor();
+                let position = clamp_to_window(cursor + TOOLTIP_CURSOR_OFFSET, size, window);
+                node.left = px(position.x);
+                node.top = px(position.y);
+                if size != Vec2::ZERO && *visibility == Visibility::Hidden {
+                    *visibility = Visibility::Inherited;
+                }
+                has_panel = true;
+            }
+            _ => commands.entity(panel_entity).despawn(),
+        }
+    }
+
+    if let (Some((owner, _, tooltip)), Some(cursor)) = (hovered, cursor)
+        && !has_panel
+    {
+        let position = clamp_to_window(cursor + TOOLTIP_CURSOR_OFFSET, Vec2::ZERO, window);
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: px(position.x),
+                    top: px(position.y),
+                    padding: UiRect::axes(px(theme.spacing.sm), px(theme.spacing.xs)),
+                    border: UiRect::all(px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(theme.colors.surface),
+                BorderColor::all(theme.colors.border),
+                ZIndex(20),
+                Visibility::Hidden,
+                Pickable::IGNORE,
+                TooltipPanel { owner },
+            ))
+            .with_children(|panel| {
+                panel.spawn(text_styled(
+                    tooltip.text.clone(),
+                    TextLevel::Small,
+                    &theme,
+                    &fonts,
+                ));
+            });
+    }
+}
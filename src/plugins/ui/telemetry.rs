@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Name of the action component a button was spawned with, used for telemetry
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ButtonActionName(pub &'static str);
+
+impl ButtonActionName {
+    /// Short type name of the action component (without module path)
+    pub fn of<T>() -> Self {
+        let full = std::any::type_name::<T>();
+        Self(full.rsplit("::").next().unwrap_or(full))
+    }
+}
+
+/// Message emitted on every button press while telemetry is enabled
+///
+/// Presses on disabled buttons are not reported; hold-to-repeat buttons
+/// report every repeat.
+#[derive(Message, Clone, Debug)]
+pub struct ButtonTelemetry {
+    pub button: Entity,
+    pub action: &'static str,
+    /// Time since app startup
+    pub timestamp: Duration,
+}
+
+/// Toggle for button press telemetry, off by default
+#[derive(Resource, Default)]
+pub struct ButtonTelemetryConfig {
+    pub enabled: bool,
+}
@@ -0,0 +1,112 @@
+use crate::plugins::core::{
+    GlobalAction, GlobalInputController, MenuNavigationAction, MenuNavigationInputController,
+};
+use crate::plugins::ui::button_builder::ButtonNavigationBuilder;
+use crate::plugins::ui::input_context::InputContextStack;
+use crate::plugins::ui::navigation::{
+    NavigationBumped, NavigationConfig, NavigationEvent, NavigationGraph,
+};
+use crate::plugins::ui::overlays::{OverlayChanged, OverlayMessage};
+use crate::plugins::ui::resources::{
+    AccessibilitySettings, CoveredNavigation, MenuBackStack, MenuStack,
+};
+use crate::plugins::ui::styles::Theme;
+use crate::plugins::ui::systems::navigation::ButtonStyleResource;
+use crate::plugins::ui::toast::ToastMessage;
+use crate::state::{GameState, OverlayState};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use leafwing_input_manager::prelude::ActionState;
+
+/// Headless App with the UI resources, states and messages but no window
+///
+/// Systems under test are added by the caller; assets and fonts are not
+/// available, so only logic that doesn't spawn text can run. Drive input by
+/// pressing actions on the controller returned by `spawn_navigation_controller`
+/// and calling `app.update()`.
+pub fn minimal_ui_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .init_state::<GameState>()
+        .init_state::<OverlayState>()
+        .init_resource::<MenuStack>()
+        .init_resource::<CoveredNavigation>()
+        .init_resource::<MenuBackStack>()
+        .init_resource::<InputContextStack>()
+        .init_resource::<NavigationGraph>()
+        .init_resource::<NavigationConfig>()
+        .init_resource::<ButtonStyleResource>()
+        .init_resource::<Theme>()
+        .init_resource::<AccessibilitySettings>()
+        .add_message::<ToastMessage>()
+        .add_message::<NavigationBumped>()
+        .add_message::<NavigationEvent>()
+        .add_message::<OverlayMessage>()
+        .add_message::<OverlayChanged>();
+    app
+}
+
+/// Spawn the menu navigation input entity read by `handle_navigation_input`
+pub fn spawn_navigation_controller(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn((
+            ActionState::<MenuNavigationAction>::default(),
+            MenuNavigationInputController,
+        ))
+        .id()
+}
+
+/// Spawn the global input entity read by the overlay and Back systems
+pub fn spawn_global_controller(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn((
+            ActionState::<GlobalAction>::default(),
+            GlobalInputController,
+        ))
+        .id()
+}
+
+/// Spawn `count` bare buttons, in navigation order
+pub fn spawn_buttons(app: &mut App, count: usize) -> Vec<Entity> {
+    (0..count)
+        .map(|_| app.world_mut().spawn(Button).id())
+        .collect()
+}
+
+/// Run `ButtonNavigationBuilder::build` against the app's NavigationGraph
+///
+/// Commands queued by the builder (Focusable, Focused, Disabled) are applied
+/// before returning.
+pub fn build_navigation(
+    app: &mut App,
+    builder: ButtonNavigationBuilder,
+    set_initial_focus: bool,
+) -> Option<Entity> {
+    let mut builder = Some(builder);
+    app.world_mut()
+        .run_system_once(
+            move |mut commands: Commands, mut nav_graph: ResMut<NavigationGraph>| {
+                builder.take().and_then(|builder| {
+                    builder.build(&mut commands, &mut nav_graph, set_initial_focus)
+                })
+            },
+        )
+        .expect("navigation build system should run")
+}
+
+/// Press a menu navigation action on the controller for the next update
+pub fn press(app: &mut App, controller: Entity, action: MenuNavigationAction) {
+    app.world_mut()
+        .get_mut::<ActionState<MenuNavigationAction>>(controller)
+        .expect("controller spawned by spawn_navigation_controller")
+        .press(&action);
+}
+
+/// Press a global action on the controller for the next update
+pub fn press_global(app: &mut App, controller: Entity, action: GlobalAction) {
+    app.world_mut()
+        .get_mut::<ActionState<GlobalAction>>(controller)
+        .expect("controller spawned by spawn_global_controller")
+        .press(&action);
+}
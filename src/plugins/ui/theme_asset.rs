@@ -0,0 +1,40 @@
+use crate::plugins::ui::styles::{Theme, ThemeLoadError};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+
+/// Theme file loaded at startup; edits replace the Theme resource
+pub const THEME_ASSET_PATH: &str = "themes/dark.theme.ron";
+
+/// Theme parsed from a `*.theme.ron` asset
+#[derive(Asset, TypePath, Clone)]
+pub struct ThemeAsset(pub Theme);
+
+/// Handle of the theme asset the Theme resource follows
+#[derive(Resource)]
+pub struct ThemeSource(pub Handle<ThemeAsset>);
+
+/// Loads `*.theme.ron` files into ThemeAsset
+#[derive(Default, TypePath)]
+pub struct ThemeAssetLoader;
+
+impl AssetLoader for ThemeAssetLoader {
+    type Asset = ThemeAsset;
+    type Settings = ();
+    type Error = ThemeLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ThemeAsset(Theme::from_ron_bytes(&bytes)?))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme.ron"]
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// How long a toast stays on screen
+pub const TOAST_DURATION_SECS: f32 = 2.0;
+
+/// Message requesting a short-lived notification at the bottom of the screen
+#[derive(Message)]
+pub struct ToastMessage {
+    pub text: String,
+}
+
+impl ToastMessage {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+/// Component on a spawned toast, despawned when its timer finishes
+#[derive(Component)]
+pub struct Toast {
+    pub timer: Timer,
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+/// Offset of the tooltip panel from the cursor
+pub const TOOLTIP_CURSOR_OFFSET: Vec2 = Vec2::new(12.0, 16.0);
+
+/// Text shown in a floating panel while the entity is hovered
+///
+/// The entity needs an `Interaction` component to receive hover state.
+#[derive(Component)]
+pub struct Tooltip {
+    pub text: String,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+/// Component on a spawned tooltip panel, pointing back at the hovered entity
+#[derive(Component)]
+pub struct TooltipPanel {
+    pub owner: Entity,
+}
@@ -1,13 +1,25 @@
 use bevy::ecs::relationship::{RelatedSpawnerCommands};
+use bevy::ecs::system::EntityCommands;
 use bevy::ecs::hierarchy::ChildOf;
 use bevy::prelude::*;
 
-use crate::plugins::ui::styles::{Theme, ThemedColor, ThemeSpacing};
+use crate::plugins::ui::components::{
+    ScrollAxis, Scrollable, SpacingRoles, ThemedBackground, ThemedBorder, ThemedFontSize,
+    ThemedText, TruncatedText,
+};
+use crate::plugins::ui::fonts::FontProvider;
+use crate::plugins::ui::styles::{Theme, ThemedColor, ThemeSpacing, Typography};
 
 /// 容器Builder - 用于构建布局容器
 pub struct ContainerBuilder {
     node: Node,
     background_color: Option<Color>,
+    border_color: Option<Color>,
+    border_radius: Option<BorderRadius>,
+    spacing_roles: SpacingRoles,
+    background_role: Option<ThemedColor>,
+    border_role: Option<ThemedColor>,
+    scroll: Option<ScrollAxis>,
 }
 
 impl ContainerBuilder {
@@ -16,6 +28,12 @@ impl ContainerBuilder {
         Self {
             node: Node::default(),
             background_color: None,
+            border_color: None,
+            border_radius: None,
+            spacing_roles: SpacingRoles::default(),
+            background_role: None,
+            border_role: None,
+            scroll: None,
         }
     }
 
@@ -31,9 +49,28 @@ impl ContainerBuilder {
                 ..default()
             },
             background_color: None,
+            border_color: None,
+            border_radius: None,
+            spacing_roles: SpacingRoles::default(),
+            background_role: None,
+            border_role: None,
+            scroll: None,
         }
     }
 
+    /// 创建卡片/面板容器 (垂直布局)
+    ///
+    /// 设置 `surface` 背景、`MD` 内边距、1px `border` 颜色边框和 `SM` 大小的圆角，
+    /// 后续链式调用可以覆盖这些值
+    pub fn card(theme: &Theme) -> Self {
+        Self::new()
+            .vertical()
+            .background_themed(ThemedColor::Surface, theme)
+            .padding_themed(ThemeSpacing::MD, theme)
+            .border_themed(UiRect::all(Val::Px(1.0)), ThemedColor::Border, theme)
+            .rounded(theme.spacing.sm)
+    }
+
     // === 布局方向 ===
 
     pub fn vertical(mut self) -> Self {
@@ -79,6 +116,18 @@ impl ContainerBuilder {
         self
     }
 
+    /// 覆盖父容器在交叉轴上对该容器的对齐方式
+    pub fn align_self(mut self, align_self: AlignSelf) -> Self {
+        self.node.align_self = align_self;
+        self
+    }
+
+    /// 设置换行后各行在交叉轴上的分布方式
+    pub fn align_content(mut self, align_content: AlignContent) -> Self {
+        self.node.align_content = align_content;
+        self
+    }
+
     // === 尺寸设置 ===
 
     pub fn width(mut self, width: Val) -> Self {
@@ -97,43 +146,70 @@ impl ContainerBuilder {
         self
     }
 
+    // === 弹性布局 ===
+
+    /// 主轴上分配剩余空间的比例，例如侧栏固定、主面板 `flex_grow(1.0)` 撑满
+    pub fn flex_grow(mut self, grow: f32) -> Self {
+        self.node.flex_grow = grow;
+        self
+    }
+
+    /// 空间不足时的收缩比例，设为 0.0 可保持固定尺寸
+    pub fn flex_shrink(mut self, shrink: f32) -> Self {
+        self.node.flex_shrink = shrink;
+        self
+    }
+
+    /// 分配剩余空间前在主轴上的初始尺寸
+    pub fn flex_basis(mut self, basis: Val) -> Self {
+        self.node.flex_basis = basis;
+        self
+    }
+
     // === 间距设置 (使用主题间距) ===
 
     pub fn padding(mut self, padding: UiRect) -> Self {
         self.node.padding = padding;
+        self.spacing_roles.padding = None;
         self
     }
 
     pub fn padding_all(mut self, value: f32) -> Self {
         self.node.padding = UiRect::all(Val::Px(value));
+        self.spacing_roles.padding = None;
         self
     }
 
     pub fn padding_themed(mut self, theme_spacing: ThemeSpacing, theme: &Theme) -> Self {
         let value = theme_spacing.get_value(&theme.spacing);
         self.node.padding = UiRect::all(Val::Px(value));
+        self.spacing_roles.padding = Some(theme_spacing);
         self
     }
 
     pub fn margin(mut self, margin: UiRect) -> Self {
         self.node.margin = margin;
+        self.spacing_roles.margin = None;
         self
     }
 
     pub fn margin_all(mut self, value: f32) -> Self {
         self.node.margin = UiRect::all(Val::Px(value));
+        self.spacing_roles.margin = None;
         self
     }
 
     pub fn margin_themed(mut self, theme_spacing: ThemeSpacing, theme: &Theme) -> Self {
         let value = theme_spacing.get_value(&theme.spacing);
         self.node.margin = UiRect::all(Val::Px(value));
+        self.spacing_roles.margin = Some(theme_spacing);
         self
     }
 
     pub fn gap(mut self, gap: Val) -> Self {
         self.node.row_gap = gap;
         self.node.column_gap = gap;
+        self.spacing_roles.gap = None;
         self
     }
 
@@ -141,6 +217,7 @@ impl ContainerBuilder {
         let value = theme_spacing.get_value(&theme.spacing);
         self.node.row_gap = Val::Px(value);
         self.node.column_gap = Val::Px(value);
+        self.spacing_roles.gap = Some(theme_spacing);
         self
     }
 
@@ -148,24 +225,97 @@ impl ContainerBuilder {
 
     pub fn background(mut self, color: Color) -> Self {
         self.background_color = Some(color);
+        self.background_role = None;
         self
     }
 
     pub fn background_themed(mut self, themed_color: ThemedColor, theme: &Theme) -> Self {
         self.background_color = Some(themed_color.get_color(&theme.colors));
+        self.background_role = Some(themed_color);
+        self
+    }
+
+    // === 边框与圆角 ===
+
+    /// 设置边框宽度和颜色
+    pub fn border(mut self, width: UiRect, color: Color) -> Self {
+        self.node.border = width;
+        self.border_color = Some(color);
+        self.border_role = None;
+        self
+    }
+
+    /// 设置边框宽度和主题颜色，切换主题时随之刷新
+    pub fn border_themed(mut self, width: UiRect, themed_color: ThemedColor, theme: &Theme) -> Self {
+        self.node.border = width;
+        self.border_color = Some(themed_color.get_color(&theme.colors));
+        self.border_role = Some(themed_color);
+        self
+    }
+
+    pub fn border_radius(mut self, radius: BorderRadius) -> Self {
+        self.border_radius = Some(radius);
+        self
+    }
+
+    /// 四角使用相同的圆角半径 (像素)
+    pub fn rounded(self, radius: f32) -> Self {
+        self.border_radius(BorderRadius::all(Val::Px(radius)))
+    }
+
+    // === 滚动 ===
+
+    /// 内容超出时裁剪并可滚动 (鼠标滚轮、PageUp/PageDown)，键盘导航时自动滚动到焦点
+    ///
+    /// 需要通过 `height` 等限制容器尺寸，内容才会溢出
+    pub fn scrollable(mut self, axis: ScrollAxis) -> Self {
+        self.node.overflow = match axis {
+            ScrollAxis::Vertical => Overflow::scroll_y(),
+        };
+        self.scroll = Some(axis);
         self
     }
 
     // === 构建方法 ===
 
+    /// 插入背景、边框等可选组件
+    fn insert_decorations(&self, entity_commands: &mut EntityCommands) {
+        if let Some(bg_color) = self.background_color {
+            entity_commands.insert(BackgroundColor(bg_color));
+        }
+
+        if let Some(border_color) = self.border_color {
+            entity_commands.insert(BorderColor::all(border_color));
+        }
+
+        if let Some(border_radius) = self.border_radius {
+            entity_commands.insert(border_radius);
+        }
+
+        if !self.spacing_roles.is_empty() {
+            entity_commands.insert(self.spacing_roles);
+        }
+
+        // 记录颜色角色，切换主题时刷新
+        if let Some(role) = self.background_role {
+            entity_commands.insert(ThemedBackground(role));
+        }
+
+        if let Some(role) = self.border_role {
+            entity_commands.insert(ThemedBorder(role));
+        }
+
+        if let Some(axis) = self.scroll {
+            entity_commands.insert(Scrollable { axis });
+        }
+    }
+
     /// Spawn容器并返回Entity，支持闭包添加子元素
     pub fn spawn(self, commands: &mut Commands, build_children: impl FnOnce(&mut RelatedSpawnerCommands<ChildOf>)) -> Entity
     {
-        let mut entity_commands = commands.spawn(self.node);
+        let mut entity_commands = commands.spawn(self.node.clone());
 
-        if let Some(bg_color) = self.background_color {
-            entity_commands.insert(BackgroundColor(bg_color));
-        }
+        self.insert_decorations(&mut entity_commands);
 
         entity_commands.with_children(build_children);
 
@@ -180,11 +330,26 @@ impl ContainerBuilder {
         build_children: impl FnOnce(&mut RelatedSpawnerCommands<ChildOf>),
     ) -> Entity
     {
-        let mut entity_commands = commands.spawn((self.node, bundle));
+        let mut entity_commands = commands.spawn((self.node.clone(), bundle));
 
-        if let Some(bg_color) = self.background_color {
-            entity_commands.insert(BackgroundColor(bg_color));
-        }
+        self.insert_decorations(&mut entity_commands);
+
+        entity_commands.with_children(build_children);
+
+        entity_commands.id()
+    }
+
+    /// 在已有父节点下spawn容器并添加额外组件
+    pub fn spawn_in<B: Bundle>(
+        self,
+        parent: &mut RelatedSpawnerCommands<ChildOf>,
+        bundle: B,
+        build_children: impl FnOnce(&mut RelatedSpawnerCommands<ChildOf>),
+    ) -> Entity
+    {
+        let mut entity_commands = parent.spawn((self.node.clone(), bundle));
+
+        self.insert_decorations(&mut entity_commands);
 
         entity_commands.with_children(build_children);
 
@@ -193,22 +358,18 @@ impl ContainerBuilder {
 
     /// Spawn容器但不添加子元素
     pub fn spawn_empty(self, commands: &mut Commands) -> Entity {
-        let mut entity_commands = commands.spawn(self.node);
+        let mut entity_commands = commands.spawn(self.node.clone());
 
-        if let Some(bg_color) = self.background_color {
-            entity_commands.insert(BackgroundColor(bg_color));
-        }
+        self.insert_decorations(&mut entity_commands);
 
         entity_commands.id()
     }
 
     /// Spawn容器和额外组件，但不添加子元素
     pub fn spawn_empty_with<B: Bundle>(self, commands: &mut Commands, bundle: B) -> Entity {
-        let mut entity_commands = commands.spawn((self.node, bundle));
+        let mut entity_commands = commands.spawn((self.node.clone(), bundle));
 
-        if let Some(bg_color) = self.background_color {
-            entity_commands.insert(BackgroundColor(bg_color));
-        }
+        self.insert_decorations(&mut entity_commands);
 
         entity_commands.id()
     }
@@ -225,37 +386,57 @@ impl Default for ContainerBuilder {
 /// 文本级别枚举
 #[derive(Clone, Copy, Debug)]
 pub enum TextLevel {
-    H1,    // 大标题
-    H2,    // 次标题
-    H3,    // 小标题
-    Body,  // 正文
-    Small, // 小文本
+    Display, // 超大号文本 (计分板)
+    H1,      // 大标题
+    H2,      // 次标题
+    H3,      // 小标题
+    Body,    // 正文
+    Small,   // 小文本
+    Caption, // 说明文字
 }
 
-/// 创建文本组件元组
+impl TextLevel {
+    /// 该级别缩放后的字号
+    pub fn font_size(&self, typography: &Typography) -> f32 {
+        typography.scaled(match self {
+            TextLevel::Display => typography.size_display,
+            TextLevel::H1 => typography.size_h1,
+            TextLevel::H2 => typography.size_h2,
+            TextLevel::H3 => typography.size_h3,
+            TextLevel::Body => typography.size_body,
+            TextLevel::Small => typography.size_small,
+            TextLevel::Caption => typography.size_caption,
+        })
+    }
+}
+
+/// 创建文本组件元组，文本颜色随主题切换刷新
 pub fn text_styled(
     text: impl Into<String>,
     level: TextLevel,
     theme: &Theme,
-    asset_server: &AssetServer,
-) -> (Text, TextFont, TextColor, TextLayout) {
-    let font_size = match level {
-        TextLevel::H1 => theme.typography.size_h1,
-        TextLevel::H2 => theme.typography.size_h2,
-        TextLevel::H3 => theme.typography.size_h3,
-        TextLevel::Body => theme.typography.size_body,
-        TextLevel::Small => theme.typography.size_small,
-    };
+    asset_server: &impl FontProvider,
+) -> (
+    Text,
+    TextFont,
+    TextColor,
+    TextLayout,
+    ThemedText,
+    ThemedFontSize,
+) {
+    let font_size = level.font_size(&theme.typography);
 
     (
         Text::new(text.into()),
         TextFont {
-            font: asset_server.load(&theme.typography.font_path),
+            font: asset_server.font(&theme.typography.font_path),
             font_size,
             ..default()
         },
         TextColor(theme.colors.text_primary),
         TextLayout::new_with_justify(Justify::Center),
+        ThemedText(ThemedColor::TextPrimary),
+        ThemedFontSize(level),
     )
 }
 
@@ -265,25 +446,52 @@ pub fn text_styled_colored(
     level: TextLevel,
     color: Color,
     theme: &Theme,
-    asset_server: &AssetServer,
-) -> (Text, TextFont, TextColor, TextLayout) {
-    let font_size = match level {
-        TextLevel::H1 => theme.typography.size_h1,
-        TextLevel::H2 => theme.typography.size_h2,
-        TextLevel::H3 => theme.typography.size_h3,
-        TextLevel::Body => theme.typography.size_body,
-        TextLevel::Small => theme.typography.size_small,
-    };
+    asset_server: &impl FontProvider,
+) -> (Text, TextFont, TextColor, TextLayout, ThemedFontSize) {
+    let font_size = level.font_size(&theme.typography);
 
     (
         Text::new(text.into()),
         TextFont {
-            font: asset_server.load(&theme.typography.font_path),
+            font: asset_server.font(&theme.typography.font_path),
             font_size,
             ..default()
         },
         TextColor(color),
         TextLayout::new_with_justify(Justify::Center),
+        ThemedFontSize(level),
+    )
+}
+
+/// 创建超出最大宽度时截断并追加"…"的单行文本
+///
+/// 截断由 `truncate_text_system` 在排版后完成，被截断时悬停显示完整文本的提示框
+pub fn text_truncated(
+    text: impl Into<String>,
+    level: TextLevel,
+    max_width: f32,
+    theme: &Theme,
+    asset_server: &impl FontProvider,
+) -> (
+    (Text, TextFont, TextColor, TextLayout, ThemedText, ThemedFontSize),
+    TruncatedText,
+    Interaction,
+) {
+    let text = text.into();
+    let (text_component, font, color, _, role, size) =
+        text_styled(text.clone(), level, theme, asset_server);
+
+    (
+        (
+            text_component,
+            font,
+            color,
+            TextLayout::new(Justify::Left, LineBreak::NoWrap),
+            role,
+            size,
+        ),
+        TruncatedText::new(text, max_width),
+        Interaction::default(),
     )
 }
 
@@ -320,3 +528,60 @@ pub fn h_spacer_themed(theme: &Theme, spacing: ThemeSpacing) -> Node {
         ..default()
     }
 }
+
+// === 定位工具 ===
+
+/// 将面板位置限制在窗口内，用于提示框、下拉框等浮动面板
+///
+/// 坐标为UI逻辑坐标 (左上角为原点)，面板比窗口大时固定在左上角
+pub fn clamp_to_window(desired: Vec2, panel_size: Vec2, window: &Window) -> Vec2 {
+    let max = (window.size() - panel_size).max(Vec2::ZERO);
+    desired.clamp(Vec2::ZERO, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PANEL: Vec2 = Vec2::new(100.0, 40.0);
+
+    #[test]
+    fn clamp_to_window_keeps_panels_inside_each_corner() {
+        let window = Window::default();
+        let max = window.size() - PANEL;
+
+        // 左上角越界
+        assert_eq!(
+            clamp_to_window(Vec2::new(-20.0, -10.0), PANEL, &window),
+            Vec2::ZERO
+        );
+        // 右上角越界
+        assert_eq!(
+            clamp_to_window(Vec2::new(window.width() - 10.0, -10.0), PANEL, &window),
+            Vec2::new(max.x, 0.0)
+        );
+        // 左下角越界
+        assert_eq!(
+            clamp_to_window(Vec2::new(-10.0, window.height() - 10.0), PANEL, &window),
+            Vec2::new(0.0, max.y)
+        );
+        // 右下角越界
+        assert_eq!(clamp_to_window(window.size(), PANEL, &window), max);
+        // 窗口内的位置保持不变
+        assert_eq!(
+            clamp_to_window(Vec2::new(50.0, 60.0), PANEL, &window),
+            Vec2::new(50.0, 60.0)
+        );
+    }
+
+    #[test]
+    fn clamp_to_window_pins_oversized_panels_to_the_top_left() {
+        let window = Window::default();
+        let oversized = window.size() + Vec2::new(200.0, 100.0);
+
+        assert_eq!(
+            clamp_to_window(Vec2::new(30.0, 40.0), oversized, &window),
+            Vec2::ZERO
+        );
+    }
+}
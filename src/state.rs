@@ -7,6 +7,10 @@ pub enum GameState {
     Match3,
 }
 
+/// The overlay on top of the MenuStack, the only one taking input
+///
+/// Overlays beneath it stay spawned; their UI lifetime follows
+/// `OverlayChanged` messages rather than OnEnter/OnExit of this state.
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum OverlayState {
     #[default]
@@ -14,4 +18,5 @@ pub enum OverlayState {
     Settings,
     PauseMenu,
     ExitMenu,
+    Alert,
 }